@@ -0,0 +1,34 @@
+// ANSI colors for terminal output, controlled by the `color` setting.
+
+use std::env;
+use std::io::{self, IsTerminal};
+
+use crate::config::{self, ColorMode};
+
+fn enabled() -> bool {
+    match config::get().color {
+        ColorMode::Always => true,
+        ColorMode::Never => false,
+        ColorMode::Auto => io::stdout().is_terminal() && env::var_os("NO_COLOR").is_none(),
+    }
+}
+
+fn paint(text: &str, code: &str) -> String {
+    if enabled() {
+        format!("\x1b[{}m{}\x1b[0m", code, text)
+    } else {
+        text.to_string()
+    }
+}
+
+pub fn red(text: &str) -> String {
+    paint(text, "31")
+}
+
+pub fn green(text: &str) -> String {
+    paint(text, "32")
+}
+
+pub fn yellow(text: &str) -> String {
+    paint(text, "33")
+}
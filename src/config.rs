@@ -0,0 +1,114 @@
+// Runner defaults from `aoc.toml` at the project root, overridden per key by
+// the user-level `$XDG_CONFIG_HOME/aoc/aoc.toml` (or `~/.config/aoc/aoc.toml`).
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ColorMode {
+    Auto,
+    Always,
+    Never,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub input_dir: PathBuf,
+    pub default_year: u16,
+    pub default_day: u8,
+    pub bench_iterations: u32,
+    pub fuzz_iterations: u32,
+    pub format: String,
+    pub color: ColorMode,
+    pub timeout_secs: f64,
+    pub memory_limit_mib: u64,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            input_dir: PathBuf::from("src"),
+            default_year: 2024,
+            default_day: 8,
+            bench_iterations: 10,
+            fuzz_iterations: 100,
+            format: String::from("md"),
+            color: ColorMode::Auto,
+            timeout_secs: 60.0,
+            memory_limit_mib: 4096,
+        }
+    }
+}
+
+pub struct Source {
+    pub path: PathBuf,
+    pub loaded: bool,
+}
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+pub fn project_config_path() -> PathBuf {
+    PathBuf::from("aoc.toml")
+}
+
+pub fn user_config_path() -> Option<PathBuf> {
+    env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))
+        .map(|dir| dir.join("aoc").join("aoc.toml"))
+}
+
+fn read_table(path: &Path) -> Result<Option<toml::Table>, String> {
+    match fs::read_to_string(path) {
+        Ok(content) => content
+            .parse::<toml::Table>()
+            .map(Some)
+            .map_err(|err| format!("{}: {}", path.display(), err)),
+        Err(_) => Ok(None),
+    }
+}
+
+// Later files override earlier ones key by key, missing keys keep their defaults
+pub fn load() -> Result<(Config, Vec<Source>), String> {
+    let paths = std::iter::once(project_config_path()).chain(user_config_path());
+    let mut merged = toml::Table::new();
+    let mut sources = vec![];
+
+    for path in paths {
+        let table = read_table(&path)?;
+        sources.push(Source {
+            path,
+            loaded: table.is_some(),
+        });
+        merged.extend(table.unwrap_or_default());
+    }
+
+    let config = merged
+        .try_into::<Config>()
+        .map_err(|err| format!("invalid configuration: {}", err))?;
+    Ok((config, sources))
+}
+
+pub fn init(config: Config) {
+    let _ = CONFIG.set(config);
+}
+
+pub fn get() -> &'static Config {
+    CONFIG.get_or_init(Config::default)
+}
+
+pub fn show(config: &Config, sources: &[Source]) -> String {
+    let mut out = String::new();
+    for source in sources {
+        let state = if source.loaded { "loaded" } else { "not found" };
+        out.push_str(&format!("# {} ({})\n", source.path.display(), state));
+    }
+    out.push_str(&toml::to_string(config).unwrap_or_default());
+    out
+}
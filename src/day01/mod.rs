@@ -1 +0,0 @@
-pub mod ex1;
\ No newline at end of file
@@ -1 +0,0 @@
-pub mod ex2;
\ No newline at end of file
@@ -1 +0,0 @@
-pub mod ex3;
\ No newline at end of file
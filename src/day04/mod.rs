@@ -1 +0,0 @@
-pub mod ex4;
\ No newline at end of file
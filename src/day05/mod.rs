@@ -1 +0,0 @@
-pub mod ex5;
\ No newline at end of file
@@ -1 +0,0 @@
-pub mod ex6;
\ No newline at end of file
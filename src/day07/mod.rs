@@ -1 +0,0 @@
-pub mod ex7;
\ No newline at end of file
@@ -1 +0,0 @@
-pub mod ex8;
\ No newline at end of file
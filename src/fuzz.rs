@@ -0,0 +1,129 @@
+// Differential testing: every registered implementation of a day is fed the
+// same random input and must produce the same answers.
+
+use std::panic::{self, AssertUnwindSafe};
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use crate::color;
+use crate::solver::{find_solver, part_name, Solver};
+
+type Outcome = Result<String, String>;
+
+fn run_part(solver: &Solver, variant: usize, part: u8, input: &str) -> Outcome {
+    let part = solver.variants[variant].part(part);
+    panic::catch_unwind(AssertUnwindSafe(|| part(input))).map_err(|err| {
+        match err.downcast_ref::<&str>() {
+            Some(message) => format!("panic: {}", message),
+            None => match err.downcast_ref::<String>() {
+                Some(message) => format!("panic: {}", message),
+                None => String::from("panic"),
+            },
+        }
+    })
+}
+
+// Describes the first part on which the implementations disagree, if any
+fn disagreement(solver: &Solver, input: &str) -> Option<String> {
+    for part in [1, 2] {
+        let outcomes: Vec<Outcome> = (0..solver.variants.len())
+            .map(|variant| run_part(solver, variant, part, input))
+            .collect();
+
+        if outcomes.windows(2).any(|w| w[0] != w[1]) {
+            let details = solver
+                .variants
+                .iter()
+                .zip(outcomes)
+                .map(|(variant, outcome)| {
+                    let outcome = outcome.unwrap_or_else(|err| err);
+                    format!("        {}: {}", variant.name, outcome)
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            return Some(format!("    {}:\n{}", part_name(part), details));
+        }
+    }
+    None
+}
+
+// Greedily drops lines as long as the implementations keep disagreeing
+fn shrink(solver: &Solver, input: &str) -> String {
+    let mut lines: Vec<&str> = input.lines().collect();
+    let mut index = 0;
+    while index < lines.len() {
+        let mut candidate = lines.clone();
+        candidate.remove(index);
+        if disagreement(solver, &candidate.join("\n")).is_some() {
+            lines = candidate;
+        } else {
+            index += 1;
+        }
+    }
+    lines.join("\n")
+}
+
+pub fn fuzz_diff(year: u16, day: u8, seed: u64, iterations: u32) -> bool {
+    let Some(solver) = find_solver(year, day) else {
+        println!("To be implemented soon ... ");
+        return false;
+    };
+    let Some(generate) = solver.generate else {
+        println!("No input generator available for day {:02}", day);
+        return false;
+    };
+    if solver.variants.len() < 2 {
+        println!("Day {:02} needs at least two implementations", day);
+        return false;
+    }
+
+    println!(
+        "Day {:02}: comparing {} with seed {}",
+        day,
+        solver.variant_names().join(", "),
+        seed
+    );
+
+    // Panics are reported as outcomes, so the default hook would only add noise
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut failure = None;
+    for iteration in 1..=iterations {
+        let input = generate(&mut rng);
+        if disagreement(&solver, &input).is_some() {
+            let minimal = shrink(&solver, &input);
+            let details = disagreement(&solver, &minimal).unwrap_or_default();
+            failure = Some((iteration, minimal, details));
+            break;
+        }
+    }
+
+    panic::set_hook(hook);
+
+    match failure {
+        Some((iteration, input, details)) => {
+            println!(
+                "{} at iteration {}:",
+                color::red("Implementations disagree"),
+                iteration
+            );
+            println!("{}", details);
+            println!("Minimal input:");
+            for line in input.lines() {
+                println!("    {}", line);
+            }
+            false
+        }
+        None => {
+            println!(
+                "{} iterations {}",
+                iterations,
+                color::green("without disagreement")
+            );
+            true
+        }
+    }
+}
@@ -0,0 +1,160 @@
+// Local journal of submitted answers, kept next to `results.txt`.
+// One guess per line, e.g. "2024 Day 05 Part Two: 4700 too high".
+
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use crate::config;
+use crate::solver::part_name;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    TooHigh,
+    TooLow,
+    Wrong,
+    Correct,
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().replace('-', " ").as_str() {
+            "too high" | "high" => Ok(Verdict::TooHigh),
+            "too low" | "low" => Ok(Verdict::TooLow),
+            "wrong" => Ok(Verdict::Wrong),
+            "correct" | "right" => Ok(Verdict::Correct),
+            other => Err(format!("Unknown verdict '{}'", other)),
+        }
+    }
+}
+
+impl std::fmt::Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = match self {
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::Wrong => "wrong",
+            Verdict::Correct => "correct",
+        };
+        write!(f, "{}", text)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Guess {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub value: String,
+    pub verdict: Verdict,
+}
+
+pub fn parse_part(part: &str) -> Option<u8> {
+    match part.to_lowercase().as_str() {
+        "1" | "one" => Some(1),
+        "2" | "two" => Some(2),
+        _ => None,
+    }
+}
+
+pub fn guesses_file_path() -> PathBuf {
+    config::get().input_dir.join("results").join("guesses.txt")
+}
+
+fn parse_guess(line: &str) -> Option<Guess> {
+    let (key, rest) = line.split_once(':')?;
+    let mut words = key.split_whitespace();
+    let year = words.next()?.parse().ok()?;
+    let day = match (words.next(), words.next()) {
+        (Some("Day"), Some(day)) => day.parse().ok()?,
+        _ => return None,
+    };
+    let part = (1..=2).find(|&p| key.trim_end().ends_with(part_name(p)))?;
+    let (value, verdict) = rest.trim().split_once(' ')?;
+    Some(Guess {
+        year,
+        day,
+        part,
+        value: value.to_string(),
+        verdict: verdict.parse().ok()?,
+    })
+}
+
+pub fn load_guesses() -> Vec<Guess> {
+    fs::read_to_string(guesses_file_path())
+        .map(|content| content.lines().filter_map(parse_guess).collect())
+        .unwrap_or_default()
+}
+
+pub fn record_guess(guess: &Guess) -> io::Result<()> {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(guesses_file_path())?;
+    writeln!(
+        file,
+        "{} Day {:02} {}: {} {}",
+        guess.year,
+        guess.day,
+        part_name(guess.part),
+        guess.value,
+        guess.verdict
+    )
+}
+
+pub fn check_answer(guesses: &[Guess], year: u16, day: u8, part: u8, answer: &str) -> Vec<String> {
+    let guesses: Vec<&Guess> = guesses
+        .iter()
+        .filter(|g| g.year == year && g.day == day && g.part == part)
+        .collect();
+    let mut warnings = vec![];
+
+    if let Some(rejected) = guesses
+        .iter()
+        .find(|g| g.value == answer && g.verdict != Verdict::Correct)
+    {
+        warnings.push(format!(
+            "{} was already submitted and rejected ({})",
+            answer, rejected.verdict
+        ));
+    }
+
+    if let Some(correct) = guesses.iter().find(|g| g.verdict == Verdict::Correct) {
+        if correct.value != answer {
+            warnings.push(format!(
+                "differs from the accepted answer {}",
+                correct.value
+            ));
+        }
+    }
+
+    let Ok(value) = answer.parse::<i128>() else {
+        return warnings;
+    };
+    let bound = |verdict: Verdict| {
+        guesses
+            .iter()
+            .filter(move |g| g.verdict == verdict)
+            .filter_map(|g| g.value.parse::<i128>().ok())
+    };
+    if let Some(upper) = bound(Verdict::TooHigh).min() {
+        if value >= upper {
+            warnings.push(format!(
+                "{} is not below the known upper bound {}",
+                value, upper
+            ));
+        }
+    }
+    if let Some(lower) = bound(Verdict::TooLow).max() {
+        if value <= lower {
+            warnings.push(format!(
+                "{} is not above the known lower bound {}",
+                value, lower
+            ));
+        }
+    }
+    warnings
+}
@@ -0,0 +1,165 @@
+// Runs a single day/part in a child process of this binary, so a solver that
+// loops forever or exhausts memory cannot take the whole run down with it.
+
+use std::env;
+use std::io::Read;
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::runner::{measure, read_input};
+use crate::solver::find_solver;
+
+pub struct Limits {
+    pub timeout: Duration,
+    pub memory_mib: u64,
+}
+
+pub enum Status {
+    Answer { answer: String, elapsed: Duration },
+    Timeout,
+    OutOfMemory,
+    Failed(String),
+}
+
+#[cfg(target_os = "linux")]
+fn limit_address_space(command: &mut Command, memory_mib: u64) {
+    use std::os::unix::process::CommandExt;
+
+    let bytes = memory_mib.saturating_mul(1024 * 1024);
+    // Only async-signal-safe calls are allowed between fork and exec
+    unsafe {
+        command.pre_exec(move || {
+            let limit = libc::rlimit {
+                rlim_cur: bytes as libc::rlim_t,
+                rlim_max: bytes as libc::rlim_t,
+            };
+            if libc::setrlimit(libc::RLIMIT_AS, &limit) != 0 {
+                return Err(std::io::Error::last_os_error());
+            }
+            Ok(())
+        });
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn limit_address_space(_command: &mut Command, _memory_mib: u64) {}
+
+// Drains the pipe on its own thread, a child that fills the pipe buffer would
+// otherwise block on its write and never exit
+fn read_pipe(pipe: Option<impl Read + Send + 'static>) -> thread::JoinHandle<String> {
+    thread::spawn(move || {
+        let mut output = String::new();
+        if let Some(mut pipe) = pipe {
+            pipe.read_to_string(&mut output).unwrap_or_default();
+        }
+        output
+    })
+}
+
+// Rust prints "memory allocation of N bytes failed" before aborting. With a
+// limit too low for the loader to map the binary and libc, the child dies from
+// SIGSEGV or SIGKILL before it can print anything, so a silent death by one of
+// those signals counts as out of memory too. A crash in unsafe code with the
+// same signal would be reported the same way.
+#[cfg(target_os = "linux")]
+fn out_of_memory(status: ExitStatus, stderr: &str) -> bool {
+    use std::os::unix::process::ExitStatusExt;
+
+    let silent_crash =
+        stderr.trim().is_empty() && matches!(status.signal(), Some(libc::SIGSEGV | libc::SIGKILL));
+    stderr.contains("memory allocation of") || silent_crash
+}
+
+#[cfg(not(target_os = "linux"))]
+fn out_of_memory(_status: ExitStatus, stderr: &str) -> bool {
+    stderr.contains("memory allocation of")
+}
+
+fn wait_with_timeout(child: &mut Child, timeout: Duration) -> Option<ExitStatus> {
+    let start = Instant::now();
+    loop {
+        if let Ok(Some(status)) = child.try_wait() {
+            return Some(status);
+        }
+        if start.elapsed() >= timeout {
+            child.kill().unwrap_or_default();
+            child.wait().unwrap_or_default();
+            return None;
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+}
+
+pub fn run_isolated(
+    year: u16,
+    day: u8,
+    part: u8,
+    variant: Option<&str>,
+    limits: &Limits,
+) -> Status {
+    let Ok(exe) = env::current_exe() else {
+        return Status::Failed(String::from("cannot locate the current executable"));
+    };
+    let mut command = Command::new(exe);
+    command
+        .arg("run-part")
+        .arg(day.to_string())
+        .arg(part.to_string())
+        .arg("--year")
+        .arg(year.to_string())
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    if let Some(name) = variant {
+        command.arg("--impl").arg(name);
+    }
+    limit_address_space(&mut command, limits.memory_mib);
+
+    let mut child = match command.spawn() {
+        Ok(child) => child,
+        Err(err) => return Status::Failed(err.to_string()),
+    };
+    let stdout = read_pipe(child.stdout.take());
+    let stderr = read_pipe(child.stderr.take());
+    let Some(status) = wait_with_timeout(&mut child, limits.timeout) else {
+        return Status::Timeout;
+    };
+
+    let stdout = stdout.join().unwrap_or_default();
+    let stderr = stderr.join().unwrap_or_default();
+    if out_of_memory(status, &stderr) {
+        return Status::OutOfMemory;
+    }
+    if !status.success() {
+        let reason = stderr.lines().find(|l| !l.trim().is_empty());
+        return Status::Failed(reason.map_or(status.to_string(), String::from));
+    }
+
+    let mut lines = stdout.lines();
+    match (
+        lines.next(),
+        lines.next().and_then(|n| n.parse::<u64>().ok()),
+    ) {
+        (Some(answer), Some(nanos)) => Status::Answer {
+            answer: answer.to_string(),
+            elapsed: Duration::from_nanos(nanos),
+        },
+        _ => Status::Failed(String::from("unexpected output from child process")),
+    }
+}
+
+// Child side: prints the answer followed by the elapsed time in nanoseconds
+pub fn run_part(year: u16, day: u8, part: u8, variant: Option<&str>) {
+    let Some(solver) = find_solver(year, day) else {
+        eprintln!("Day {:02} is not implemented", day);
+        std::process::exit(2);
+    };
+    let Some(selected) = solver.variant(variant) else {
+        eprintln!("Unknown implementation for day {:02}", day);
+        std::process::exit(2);
+    };
+    let result = measure(selected.part(part), &read_input(year, day));
+    println!("{}", result.answer);
+    println!("{}", result.elapsed.as_nanos());
+}
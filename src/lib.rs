@@ -0,0 +1,23 @@
+use std::path::PathBuf;
+
+pub mod alloc_stats;
+pub mod color;
+pub mod config;
+pub mod fuzz;
+pub mod guesses;
+pub mod isolation;
+pub mod progress;
+pub mod report;
+pub mod results;
+pub mod runner;
+pub mod solver;
+pub mod utils;
+pub mod year2024;
+
+pub fn input_file_path(year: u16, day: u8, file_name: &str) -> PathBuf {
+    config::get()
+        .input_dir
+        .join(format!("year{}", year))
+        .join(format!("day{:02}", day))
+        .join(file_name)
+}
@@ -1,24 +1,15 @@
 use std::{
-    env, io,
-    path::{Path, PathBuf},
+    collections::{HashMap, HashSet},
+    env, fs, io, process,
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
 };
 
-mod day01;
-mod day02;
-mod day03;
-mod day04;
-mod day05;
-mod day06;
-mod day07;
-mod day08;
-
-fn input_file_path(day_number: u16, file_name: &str) -> PathBuf {
-    Path::new("src")
-        .join(format!("day{:02}", day_number))
-        .join(file_name)
-}
+use advent_of_rust_2024::isolation::{self, Limits};
+use advent_of_rust_2024::runner::{self, RunOptions};
+use advent_of_rust_2024::{config, fuzz, guesses, progress, report, year2024};
 
-fn interactive_mode() {
+fn interactive_mode(year: u16) {
     println!("Choose a number between 1 and 24:");
 
     let mut input = String::new();
@@ -27,8 +18,8 @@ fn interactive_mode() {
         .expect("Failed to read input");
 
     match input.trim().parse::<u8>() {
-        Ok(num) if num >= 1 && num <= 24 => {
-            exec_day(num);
+        Ok(num) if (1..=24).contains(&num) => {
+            exec_day(year, num);
         }
         _ => {
             println!("Invalid input. Please enter a number between 1 and 24.");
@@ -36,29 +27,235 @@ fn interactive_mode() {
     }
 }
 
-fn exec_day(day: u8) {
-    match day {
-        1 => day01::ex1::exec(&input_file_path(1, "input.txt")),
-        2 => day02::ex2::exec(&input_file_path(2, "input.txt")),
-        3 => day03::ex3::exec(&input_file_path(3, "input.txt")),
-        4 => day04::ex4::exec(&input_file_path(4, "input.txt")),
-        5 => day05::ex5::exec(&input_file_path(5, "input.txt")),
-        6 => day06::ex6::exec(&input_file_path(6, "input.txt")),
-        7 => day07::ex7::exec(&input_file_path(7, "input.txt")),
-        8 => day08::ex8::exec(&input_file_path(8, "input.txt")),
+fn exec_day(year: u16, day: u8) {
+    match year {
+        year2024::YEAR => year2024::exec_day(day),
         _ => println!("To be implemented soon ... "),
     }
 }
 
+fn record_guess(year: u16, args: &[String]) {
+    let [day, part, value, verdict @ ..] = args else {
+        println!("Usage: guess <day> <one|two> <value> <too-high|too-low|wrong|correct>");
+        return;
+    };
+    let guess = match (
+        day.parse::<u8>(),
+        guesses::parse_part(part),
+        verdict.join(" ").parse(),
+    ) {
+        (Ok(day), Some(part), Ok(verdict)) => guesses::Guess {
+            year,
+            day,
+            part,
+            value: value.clone(),
+            verdict,
+        },
+        (_, _, Err(err)) => {
+            println!("{}", err);
+            return;
+        }
+        _ => {
+            println!("Invalid day or part.");
+            return;
+        }
+    };
+    match guesses::record_guess(&guess) {
+        Ok(()) => println!(
+            "Recorded guess {} for {} day {:02} as {}.",
+            guess.value, guess.year, guess.day, guess.verdict
+        ),
+        Err(err) => println!("Failed to record guess: {}", err),
+    }
+}
+
+fn write_report(format: &str, output: Option<&str>) {
+    let format = match format.parse::<report::Format>() {
+        Ok(format) => format,
+        Err(err) => {
+            println!("{}", err);
+            return;
+        }
+    };
+    let content = report::render(format, &report::collect_reports());
+    match output {
+        Some(path) => match fs::write(path, content) {
+            Ok(()) => println!("Report written to {}", path),
+            Err(err) => println!("Failed to write report: {}", err),
+        },
+        None => print!("{}", content),
+    }
+}
+
+// Flags followed by a value, the others are plain switches
+const VALUE_FLAGS: [&str; 8] = [
+    "--year",
+    "--impl",
+    "--timeout",
+    "--memory",
+    "--seed",
+    "--iterations",
+    "--format",
+    "--output",
+];
+const SWITCHES: [&str; 2] = ["--explain", "--isolate"];
+
+fn fail(message: String) -> ! {
+    eprintln!("{}", message);
+    process::exit(2);
+}
+
+// Command line with the flags taken out, so flags may appear anywhere, e.g.
+// `--year 2024 run 3` or `run --explain 3`
+struct Args {
+    positional: Vec<String>,
+    values: HashMap<String, String>,
+    switches: HashSet<String>,
+}
+
+impl Args {
+    fn parse(mut args: impl Iterator<Item = String>) -> Args {
+        let mut parsed = Args {
+            positional: vec![],
+            values: HashMap::new(),
+            switches: HashSet::new(),
+        };
+        while let Some(arg) = args.next() {
+            if VALUE_FLAGS.contains(&arg.as_str()) {
+                let value = args
+                    .next()
+                    .unwrap_or_else(|| fail(format!("Missing value for {}", arg)));
+                parsed.values.insert(arg, value);
+            } else if SWITCHES.contains(&arg.as_str()) {
+                parsed.switches.insert(arg);
+            } else if arg.starts_with("--") {
+                fail(format!("Unknown flag {}", arg));
+            } else {
+                parsed.positional.push(arg);
+            }
+        }
+        parsed
+    }
+
+    fn positional(&self, index: usize) -> Option<&str> {
+        self.positional.get(index).map(String::as_str)
+    }
+
+    fn value(&self, flag: &str) -> Option<&str> {
+        self.values.get(flag).map(String::as_str)
+    }
+
+    fn switch(&self, flag: &str) -> bool {
+        self.switches.contains(flag)
+    }
+
+    // None if the flag is absent, exits if its value does not parse
+    fn parsed<T: FromStr>(&self, flag: &str) -> Option<T> {
+        self.value(flag).map(|value| {
+            value
+                .parse()
+                .unwrap_or_else(|_| fail(format!("Invalid value for {}: {}", flag, value)))
+        })
+    }
+
+    // Day at the given position, the default if it is missing
+    fn day(&self, index: usize, default: u8) -> u8 {
+        let Some(arg) = self.positional(index) else {
+            return default;
+        };
+        match arg.parse::<u8>() {
+            Ok(day) if (1..=25).contains(&day) => day,
+            _ => fail(format!("Invalid day '{}', expected 1 to 25", arg)),
+        }
+    }
+}
+
+// Flags may appear anywhere, every command accepts --year <year>, defaulting
+// to `default_year` from aoc.toml. Invalid values and unknown flags are errors.
 // cargo run
 // cargo run interactive
+// cargo run run <day> [--impl <name>] [--explain] [--isolate]
+// cargo run all [--isolate] [--timeout <seconds>] [--memory <MiB>]
+// cargo run bench <day> [iterations]
+// cargo run crosscheck <day>
+// cargo run fuzz-diff <day> [--seed <seed>] [--iterations <n>]
+// cargo run report [--format md|html] [--output <file>]
+// cargo run guess <day> <one|two> <value> <too-high|too-low|wrong|correct>
+// cargo run config show
+// cargo run --features alloc-stats run <day>
 fn main() {
-    let current_day: u8 = 8;
+    let (config, sources) = config::load().unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        process::exit(1);
+    });
+    config::init(config);
+    let config = config::get();
+    let current_day = config.default_day;
 
-    let args: Vec<String> = env::args().collect();
-    if args.len() > 1 && args[1] == "interactive" {
-        interactive_mode();
-    } else {
-        exec_day(current_day);
+    let args = Args::parse(env::args().skip(1));
+    let year_flag = args.parsed::<u16>("--year");
+    let year = year_flag.unwrap_or(config.default_year);
+    let isolation = args.switch("--isolate").then(|| Limits {
+        timeout: Duration::from_secs_f64(args.parsed("--timeout").unwrap_or(config.timeout_secs)),
+        memory_mib: args.parsed("--memory").unwrap_or(config.memory_limit_mib),
+    });
+    let options = RunOptions {
+        variant: args.value("--impl"),
+        explain: args.switch("--explain"),
+        isolation,
+    };
+    match args.positional(0) {
+        None => exec_day(year, current_day),
+        Some("interactive") => interactive_mode(year),
+        Some("run") => {
+            progress::enable();
+            runner::run_day(year, args.day(1, current_day), &options)
+        }
+        Some("all") => {
+            progress::enable();
+            runner::run_all(year_flag, &options)
+        }
+        Some("run-part") => {
+            let part = match args.positional(2) {
+                None => 1,
+                Some(part @ ("1" | "2")) => part.parse().unwrap(),
+                Some(part) => fail(format!("Invalid part '{}', expected 1 or 2", part)),
+            };
+            isolation::run_part(year, args.day(1, current_day), part, options.variant)
+        }
+        Some("bench") => {
+            let iterations = args.positional(2).map_or(config.bench_iterations, |n| {
+                n.parse::<u32>()
+                    .unwrap_or_else(|_| fail(format!("Invalid iteration count '{}'", n)))
+            });
+            runner::bench_day(year, args.day(1, current_day), iterations)
+        }
+        Some("crosscheck") => {
+            if !runner::crosscheck_day(year, args.day(1, current_day)) {
+                process::exit(1);
+            }
+        }
+        Some("fuzz-diff") => {
+            let seed = args.parsed("--seed").unwrap_or_else(|| {
+                SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map_or(0, |time| time.as_secs())
+            });
+            let iterations = args
+                .parsed("--iterations")
+                .unwrap_or(config.fuzz_iterations);
+            if !fuzz::fuzz_diff(year, args.day(1, current_day), seed, iterations) {
+                process::exit(1);
+            }
+        }
+        Some("report") => write_report(
+            args.value("--format").unwrap_or(&config.format),
+            args.value("--output"),
+        ),
+        Some("guess") => record_guess(year, &args.positional[1..]),
+        Some("config") if args.positional(1) == Some("show") => {
+            print!("{}", config::show(config, &sources))
+        }
+        Some(command) => fail(format!("Unknown command '{}'", command)),
     }
 }
@@ -0,0 +1,83 @@
+// Progress reporting for long-running parts.
+// Solvers call `start` with the number of steps before a long loop and `tick`
+// once per step. Nothing is drawn unless the CLI called `enable`.
+
+use std::io::{self, IsTerminal, Write};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+const BAR_WIDTH: usize = 30;
+const REDRAW_INTERVAL: Duration = Duration::from_millis(100);
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static TOTAL: AtomicU64 = AtomicU64::new(0);
+static DONE: AtomicU64 = AtomicU64::new(0);
+static TIMES: Mutex<Option<(Instant, Instant)>> = Mutex::new(None);
+
+// Renders only when both stdout and stderr are terminals, so piped output stays clean
+pub fn enable() {
+    let tty = io::stdout().is_terminal() && io::stderr().is_terminal();
+    ENABLED.store(tty, Ordering::Relaxed);
+}
+
+pub fn start(total: usize) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+    TOTAL.store(total as u64, Ordering::Relaxed);
+    DONE.store(0, Ordering::Relaxed);
+    let now = Instant::now();
+    *TIMES.lock().unwrap() = Some((now, now));
+}
+
+pub fn tick() {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+    let done = DONE.fetch_add(1, Ordering::Relaxed) + 1;
+
+    let mut times = TIMES.lock().unwrap();
+    let Some((started, last_draw)) = times.as_mut() else {
+        return;
+    };
+    if last_draw.elapsed() < REDRAW_INTERVAL {
+        return;
+    }
+    *last_draw = Instant::now();
+    draw(done, TOTAL.load(Ordering::Relaxed), started.elapsed());
+}
+
+fn draw(done: u64, total: u64, elapsed: Duration) {
+    let total = total.max(1);
+    let ratio = (done as f64 / total as f64).min(1.0);
+    let filled = (ratio * BAR_WIDTH as f64) as usize;
+    let eta = if done > 0 {
+        elapsed.mul_f64((total.saturating_sub(done)) as f64 / done as f64)
+    } else {
+        Duration::ZERO
+    };
+
+    let mut stderr = io::stderr();
+    write!(
+        stderr,
+        "\r[{}{}] {}/{} {:3.0}% ETA {:.1}s",
+        "#".repeat(filled),
+        " ".repeat(BAR_WIDTH - filled),
+        done,
+        total,
+        ratio * 100.0,
+        eta.as_secs_f64()
+    )
+    .unwrap_or_default();
+    stderr.flush().unwrap_or_default();
+}
+
+// Clears the progress line, if one was drawn
+pub fn finish() {
+    if TIMES.lock().unwrap().take().is_some() {
+        let mut stderr = io::stderr();
+        write!(stderr, "\r\x1b[2K").unwrap_or_default();
+        stderr.flush().unwrap_or_default();
+    }
+}
@@ -0,0 +1,214 @@
+// Progress report of all days as Markdown or self-contained HTML.
+
+use std::fs;
+use std::time::Duration;
+
+use crate::input_file_path;
+use crate::results::{expected_answer, load_results};
+use crate::runner::{measure, read_input};
+use crate::solver::{part_name, solvers};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Markdown,
+    Html,
+}
+
+impl std::str::FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "md" | "markdown" => Ok(Format::Markdown),
+            "html" => Ok(Format::Html),
+            other => Err(format!("Unknown report format '{}', use md or html", other)),
+        }
+    }
+}
+
+pub enum Verification {
+    Verified,
+    Mismatch(String),
+    Unknown,
+}
+
+pub struct PartReport {
+    pub part: u8,
+    pub answer: String,
+    pub elapsed: Duration,
+    pub verification: Verification,
+}
+
+pub struct DayReport {
+    pub year: u16,
+    pub title: String,
+    pub parts: Vec<PartReport>,
+    pub learnings: Vec<String>,
+}
+
+// Puzzle titles look like "--- Day 1: Historian Hysteria ---"
+fn read_title(year: u16, day: u8) -> String {
+    fs::read_to_string(input_file_path(year, day, "part_one.txt"))
+        .ok()
+        .and_then(|text| {
+            text.lines()
+                .map(str::trim)
+                .find(|line| line.starts_with("--- Day"))
+                .map(|line| line.trim_matches(|c| c == '-' || c == ' ').to_string())
+        })
+        .unwrap_or_else(|| format!("Day {}", day))
+}
+
+// Bullet points of the "Learnings and Takeaways" comment at the top of a solution
+fn read_learnings(text: &str) -> Vec<String> {
+    let Some(start) = text.find("/* Learnings and Takeaways:") else {
+        return vec![];
+    };
+    let end = text[start..]
+        .find("*/")
+        .map_or(text.len(), |end| start + end);
+
+    text[start..end]
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("->"))
+        .map(|line| line.trim().to_string())
+        .collect()
+}
+
+pub fn collect_reports() -> Vec<DayReport> {
+    let results = load_results();
+
+    solvers()
+        .iter()
+        .map(|solver| {
+            let input = read_input(solver.year, solver.day);
+            let variant = solver.variant(None).unwrap();
+            let parts = [1, 2]
+                .into_iter()
+                .map(|part| {
+                    let result = measure(variant.part(part), &input);
+                    let verification =
+                        match expected_answer(&results, solver.year, solver.day, part) {
+                            Some(expected) if *expected == result.answer => Verification::Verified,
+                            Some(expected) => Verification::Mismatch(expected.clone()),
+                            None => Verification::Unknown,
+                        };
+                    PartReport {
+                        part,
+                        answer: result.answer,
+                        elapsed: result.elapsed,
+                        verification,
+                    }
+                })
+                .collect();
+
+            DayReport {
+                year: solver.year,
+                title: read_title(solver.year, solver.day),
+                parts,
+                learnings: read_learnings(solver.source),
+            }
+        })
+        .collect()
+}
+
+fn status_text(verification: &Verification) -> String {
+    match verification {
+        Verification::Verified => String::from("verified"),
+        Verification::Mismatch(expected) => format!("mismatch, expected {}", expected),
+        Verification::Unknown => String::from("unverified"),
+    }
+}
+
+pub fn render_markdown(reports: &[DayReport]) -> String {
+    let mut out = String::from("# Advent of Code\n");
+
+    for (index, report) in reports.iter().enumerate() {
+        if index == 0 || reports[index - 1].year != report.year {
+            out.push_str(&format!("\n## {}\n", report.year));
+        }
+        out.push_str(&format!("\n### {}\n\n", report.title));
+        out.push_str("| Part | Answer | Status | Time |\n");
+        out.push_str("| --- | --- | --- | --- |\n");
+        for part in &report.parts {
+            out.push_str(&format!(
+                "| {} | `{}` | {} | {:.3?} |\n",
+                part_name(part.part),
+                part.answer,
+                status_text(&part.verification),
+                part.elapsed
+            ));
+        }
+        if !report.learnings.is_empty() {
+            out.push_str("\n#### Learnings and Takeaways\n\n");
+            for learning in &report.learnings {
+                out.push_str(&format!("- {}\n", learning));
+            }
+        }
+    }
+    out
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+pub fn render_html(reports: &[DayReport]) -> String {
+    let mut out = String::from(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>Advent of Code</title>\n<style>\n\
+         body { font-family: sans-serif; max-width: 50em; margin: 2em auto; }\n\
+         table { border-collapse: collapse; }\n\
+         th, td { border: 1px solid #ccc; padding: 0.3em 0.8em; text-align: left; }\n\
+         .verified { color: #2a7a2a; }\n\
+         .mismatch { color: #b22222; }\n\
+         .unverified { color: #777; }\n\
+         </style>\n</head>\n<body>\n<h1>Advent of Code</h1>\n",
+    );
+
+    for (index, report) in reports.iter().enumerate() {
+        if index == 0 || reports[index - 1].year != report.year {
+            out.push_str(&format!("<h2>{}</h2>\n", report.year));
+        }
+        out.push_str(&format!("<h3>{}</h3>\n", escape_html(&report.title)));
+        out.push_str(
+            "<table>\n<tr><th>Part</th><th>Answer</th><th>Status</th><th>Time</th></tr>\n",
+        );
+        for part in &report.parts {
+            let class = match part.verification {
+                Verification::Verified => "verified",
+                Verification::Mismatch(_) => "mismatch",
+                Verification::Unknown => "unverified",
+            };
+            out.push_str(&format!(
+                "<tr><td>{}</td><td><code>{}</code></td><td class=\"{}\">{}</td><td>{:.3?}</td></tr>\n",
+                part_name(part.part),
+                escape_html(&part.answer),
+                class,
+                escape_html(&status_text(&part.verification)),
+                part.elapsed
+            ));
+        }
+        out.push_str("</table>\n");
+        if !report.learnings.is_empty() {
+            out.push_str("<h4>Learnings and Takeaways</h4>\n<ul>\n");
+            for learning in &report.learnings {
+                out.push_str(&format!("<li>{}</li>\n", escape_html(learning)));
+            }
+            out.push_str("</ul>\n");
+        }
+    }
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+pub fn render(format: Format, reports: &[DayReport]) -> String {
+    match format {
+        Format::Markdown => render_markdown(reports),
+        Format::Html => render_html(reports),
+    }
+}
@@ -0,0 +1,48 @@
+// Accepted answers from `src/results/results.txt`, keyed by (year, day).
+// Days are listed under a "Year 2024:" header, each with its two parts.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::config;
+use crate::solver::part_name;
+
+pub type Results = HashMap<(u16, u8), [Option<String>; 2]>;
+
+pub fn results_file_path() -> PathBuf {
+    config::get().input_dir.join("results").join("results.txt")
+}
+
+pub fn parse_results(content: &str) -> Results {
+    let mut results = Results::new();
+    let mut year = None;
+    let mut day = None;
+
+    for line in content.lines().map(str::trim) {
+        if let Some(header) = line.strip_prefix("Year ") {
+            year = header.trim_end_matches(':').parse::<u16>().ok();
+            day = None;
+        } else if let Some(header) = line.strip_prefix("Day ") {
+            day = header.trim_end_matches(':').parse::<u8>().ok();
+        } else if let (Some(year), Some(day), Some((part, answer))) =
+            (year, day, line.split_once(':'))
+        {
+            if let Some(part) = (1..=2).find(|&p| part == part_name(p)) {
+                let answers = results.entry((year, day)).or_default();
+                answers[part as usize - 1] = Some(answer.trim().to_string());
+            }
+        }
+    }
+    results
+}
+
+pub fn load_results() -> Results {
+    fs::read_to_string(results_file_path())
+        .map(|content| parse_results(&content))
+        .unwrap_or_default()
+}
+
+pub fn expected_answer(results: &Results, year: u16, day: u8, part: u8) -> Option<&String> {
+    results.get(&(year, day))?[part as usize - 1].as_ref()
+}
@@ -0,0 +1,243 @@
+use std::fs;
+use std::time::{Duration, Instant};
+
+use crate::alloc_stats::{self, AllocStats};
+use crate::color;
+use crate::guesses::{check_answer, load_guesses};
+use crate::input_file_path;
+use crate::isolation::{run_isolated, Limits, Status};
+use crate::progress;
+use crate::solver::{find_solver, part_name, solvers, PartFn, Solver, Variant};
+
+pub struct Measurement {
+    pub answer: String,
+    pub elapsed: Duration,
+    pub alloc: Option<AllocStats>,
+}
+
+pub fn measure(part: PartFn, input: &str) -> Measurement {
+    let start = Instant::now();
+    let (answer, alloc) = alloc_stats::track(|| part(input));
+    progress::finish();
+    Measurement {
+        answer,
+        elapsed: start.elapsed(),
+        alloc,
+    }
+}
+
+pub fn read_input(year: u16, day: u8) -> String {
+    fs::read_to_string(input_file_path(year, day, "input.txt")).unwrap()
+}
+
+fn print_alloc(alloc: &Option<AllocStats>) {
+    if let Some(stats) = alloc {
+        println!("        {}", stats);
+    }
+}
+
+fn name_width(solver: &Solver) -> usize {
+    solver
+        .variant_names()
+        .iter()
+        .map(|name| name.len())
+        .max()
+        .unwrap_or(0)
+}
+
+fn select_variant<'a>(solver: &'a Solver, name: Option<&str>) -> Option<&'a Variant> {
+    let variant = solver.variant(name);
+    if variant.is_none() {
+        println!(
+            "Unknown implementation '{}' for day {:02}, available: {}",
+            name.unwrap_or_default(),
+            solver.day,
+            solver.variant_names().join(", ")
+        );
+    }
+    variant
+}
+
+#[derive(Default)]
+pub struct RunOptions<'a> {
+    pub variant: Option<&'a str>,
+    pub explain: bool,
+    pub isolation: Option<Limits>,
+}
+
+pub fn run_solver(solver: &Solver, options: &RunOptions) {
+    let Some(selected) = select_variant(solver, options.variant) else {
+        return;
+    };
+    let input = read_input(solver.year, solver.day);
+    let guesses = load_guesses();
+
+    match options.variant {
+        Some(name) => println!("Day {:02} ({}):", solver.day, name),
+        None => println!("Day {:02}:", solver.day),
+    }
+    for number in [1, 2] {
+        let name = part_name(number);
+        let result = match &options.isolation {
+            None => measure(selected.part(number), &input),
+            Some(limits) => {
+                match run_isolated(solver.year, solver.day, number, options.variant, limits) {
+                    Status::Answer { answer, elapsed } => Measurement {
+                        answer,
+                        elapsed,
+                        alloc: None,
+                    },
+                    Status::Timeout => {
+                        println!(
+                            "    {}: {} (after {:?})",
+                            name,
+                            color::red("TIMEOUT"),
+                            limits.timeout
+                        );
+                        continue;
+                    }
+                    Status::OutOfMemory => {
+                        println!(
+                            "    {}: {} (limit {} MiB)",
+                            name,
+                            color::red("OOM"),
+                            limits.memory_mib
+                        );
+                        continue;
+                    }
+                    Status::Failed(reason) => {
+                        println!("    {}: {} ({})", name, color::red("FAILED"), reason);
+                        continue;
+                    }
+                }
+            }
+        };
+        println!("    {}: {} ({:.3?})", name, result.answer, result.elapsed);
+        print_alloc(&result.alloc);
+        for warning in check_answer(&guesses, solver.year, solver.day, number, &result.answer) {
+            println!("        {}: {}", color::yellow("warning"), warning);
+        }
+    }
+}
+
+pub fn explain_solver(solver: &Solver) {
+    match solver.explain {
+        Some(explain) => {
+            println!("Explanation:");
+            for line in explain(&read_input(solver.year, solver.day)) {
+                println!("    {}", line);
+            }
+        }
+        None => println!("No explanation available for day {:02}", solver.day),
+    }
+}
+
+pub fn run_day(year: u16, day: u8, options: &RunOptions) {
+    match find_solver(year, day) {
+        Some(solver) => {
+            run_solver(&solver, options);
+            if options.explain {
+                explain_solver(&solver);
+            }
+        }
+        None => println!("To be implemented soon ... "),
+    }
+}
+
+// Runs every day of the given year, or of all years grouped by year
+pub fn run_all(year: Option<u16>, options: &RunOptions) {
+    let solvers: Vec<Solver> = solvers()
+        .into_iter()
+        .filter(|solver| year.is_none_or(|year| solver.year == year))
+        .collect();
+
+    for (index, solver) in solvers.iter().enumerate() {
+        if index > 0 {
+            println!();
+        }
+        if index == 0 || solvers[index - 1].year != solver.year {
+            println!("Year {}:", solver.year);
+            println!();
+        }
+        run_solver(solver, options);
+    }
+}
+
+// Runs every registered implementation of a day and returns whether they all agree
+pub fn crosscheck_day(year: u16, day: u8) -> bool {
+    let Some(solver) = find_solver(year, day) else {
+        println!("To be implemented soon ... ");
+        return false;
+    };
+    let input = read_input(year, day);
+    let width = name_width(&solver);
+    let mut agree = true;
+
+    let count = solver.variants.len();
+    println!(
+        "Day {:02} ({} implementation{}):",
+        day,
+        count,
+        if count == 1 { "" } else { "s" }
+    );
+    for number in [1, 2] {
+        println!("    {}:", part_name(number));
+        let answers: Vec<String> = solver
+            .variants
+            .iter()
+            .map(|variant| {
+                let answer = variant.part(number)(&input);
+                println!("        {:width$}  {}", variant.name, answer, width = width);
+                answer
+            })
+            .collect();
+
+        if answers.windows(2).all(|w| w[0] == w[1]) {
+            println!("        {}", color::green("OK"));
+        } else {
+            println!("        {}", color::red("MISMATCH"));
+            agree = false;
+        }
+    }
+    agree
+}
+
+// Timings of each implementation are shown relative to the default one
+pub fn bench_day(year: u16, day: u8, iterations: u32) {
+    let Some(solver) = find_solver(year, day) else {
+        println!("To be implemented soon ... ");
+        return;
+    };
+    let input = read_input(year, day);
+    let iterations = iterations.max(1);
+    let width = name_width(&solver);
+
+    println!("Day {:02} ({} iterations):", day, iterations);
+    for number in [1, 2] {
+        println!("    {}:", part_name(number));
+        let mut baseline: Option<Duration> = None;
+        for variant in &solver.variants {
+            let part = variant.part(number);
+            let results: Vec<Measurement> =
+                (0..iterations).map(|_| measure(part, &input)).collect();
+            let timings: Vec<Duration> = results.iter().map(|r| r.elapsed).collect();
+            let min = timings.iter().min().unwrap();
+            let max = timings.iter().max().unwrap();
+            let mean = timings.iter().sum::<Duration>() / iterations;
+            let reference = *baseline.get_or_insert(mean);
+
+            println!(
+                "        {:width$}  min {:.3?}, mean {:.3?}, max {:.3?} ({:.2}x)",
+                variant.name,
+                min,
+                mean,
+                max,
+                mean.as_secs_f64() / reference.as_secs_f64(),
+                width = width
+            );
+            if let Some(stats) = &results[0].alloc {
+                println!("        {:width$}  {}", "", stats, width = width);
+            }
+        }
+    }
+}
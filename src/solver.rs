@@ -0,0 +1,64 @@
+use rand::rngs::StdRng;
+
+use crate::year2024;
+
+pub type PartFn = fn(&str) -> String;
+pub type ExplainFn = fn(&str) -> Vec<String>;
+pub type GenerateFn = fn(&mut StdRng) -> String;
+
+pub struct Variant {
+    pub name: &'static str,
+    pub part_one: PartFn,
+    pub part_two: PartFn,
+}
+
+impl Variant {
+    pub fn part(&self, number: u8) -> PartFn {
+        match number {
+            1 => self.part_one,
+            _ => self.part_two,
+        }
+    }
+}
+
+// The first variant is the default implementation of a day
+pub struct Solver {
+    pub year: u16,
+    pub day: u8,
+    // Solution source embedded at build time, reports read its learnings from it
+    pub source: &'static str,
+    pub variants: Vec<Variant>,
+    pub explain: Option<ExplainFn>,
+    pub generate: Option<GenerateFn>,
+}
+
+impl Solver {
+    pub fn variant(&self, name: Option<&str>) -> Option<&Variant> {
+        match name {
+            Some(name) => self.variants.iter().find(|v| v.name == name),
+            None => self.variants.first(),
+        }
+    }
+
+    pub fn variant_names(&self) -> Vec<&'static str> {
+        self.variants.iter().map(|v| v.name).collect()
+    }
+}
+
+pub fn part_name(part: u8) -> &'static str {
+    match part {
+        1 => "Part One",
+        _ => "Part Two",
+    }
+}
+
+// Every registered solver, ordered by year and day
+pub fn solvers() -> Vec<Solver> {
+    year2024::solvers()
+}
+
+pub fn find_solver(year: u16, day: u8) -> Option<Solver> {
+    solvers()
+        .into_iter()
+        .find(|solver| solver.year == year && solver.day == day)
+}
@@ -0,0 +1,179 @@
+// Aho–Corasick automaton: finds every occurrence of a fixed set of byte
+// patterns in a single pass over the haystack, overlapping matches included.
+// The trie is compiled into a full transition table, which is fast for the
+// handful of short patterns puzzles use.
+
+use std::collections::VecDeque;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Match {
+    // Index of the pattern in the order they were given
+    pub pattern: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+#[derive(Clone, Debug)]
+pub struct AhoCorasick {
+    transitions: Vec<[u32; 256]>,
+    // Patterns ending in each state, including those reached through suffix links
+    outputs: Vec<Vec<usize>>,
+    lengths: Vec<usize>,
+}
+
+impl AhoCorasick {
+    // Panics on an empty pattern, which would match between every two bytes
+    pub fn new<I, P>(patterns: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<[u8]>,
+    {
+        // State 0 is the root, 0 also marks a missing trie edge while building
+        let mut transitions = vec![[0u32; 256]];
+        let mut outputs: Vec<Vec<usize>> = vec![vec![]];
+        let mut lengths = vec![];
+
+        for (id, pattern) in patterns.into_iter().enumerate() {
+            let pattern = pattern.as_ref();
+            assert!(!pattern.is_empty(), "pattern {} is empty", id);
+            let mut state = 0;
+            for &byte in pattern {
+                let next = transitions[state][byte as usize] as usize;
+                state = if next == 0 {
+                    transitions.push([0; 256]);
+                    outputs.push(vec![]);
+                    let new = transitions.len() - 1;
+                    transitions[state][byte as usize] = new as u32;
+                    new
+                } else {
+                    next
+                };
+            }
+            outputs[state].push(id);
+            lengths.push(pattern.len());
+        }
+
+        // Breadth-first, so the suffix link of every state is final before its
+        // children are visited. Missing edges are filled in from the suffix
+        // link, which turns the trie into a DFA.
+        let mut links = vec![0usize; transitions.len()];
+        let mut queue = transitions[0]
+            .iter()
+            .filter(|&&child| child != 0)
+            .map(|&child| child as usize)
+            .collect::<VecDeque<_>>();
+        while let Some(state) = queue.pop_front() {
+            let inherited = outputs[links[state]].clone();
+            outputs[state].extend(inherited);
+            let fallbacks = transitions[links[state]];
+            for (slot, &fallback) in transitions[state].iter_mut().zip(fallbacks.iter()) {
+                if *slot == 0 {
+                    *slot = fallback;
+                } else {
+                    links[*slot as usize] = fallback as usize;
+                    queue.push_back(*slot as usize);
+                }
+            }
+        }
+
+        AhoCorasick {
+            transitions,
+            outputs,
+            lengths,
+        }
+    }
+
+    pub fn pattern_count(&self) -> usize {
+        self.lengths.len()
+    }
+
+    // All matches ordered by their end position
+    pub fn find_iter<'a, H>(&'a self, haystack: &'a H) -> impl Iterator<Item = Match> + 'a
+    where
+        H: AsRef<[u8]> + ?Sized,
+    {
+        let mut state = 0;
+        haystack
+            .as_ref()
+            .iter()
+            .enumerate()
+            .flat_map(move |(i, &byte)| {
+                state = self.transitions[state][byte as usize] as usize;
+                self.outputs[state].iter().map(move |&pattern| Match {
+                    pattern,
+                    start: i + 1 - self.lengths[pattern],
+                    end: i + 1,
+                })
+            })
+    }
+
+    pub fn count<H>(&self, haystack: &H) -> usize
+    where
+        H: AsRef<[u8]> + ?Sized,
+    {
+        let mut state = 0;
+        haystack
+            .as_ref()
+            .iter()
+            .map(|&byte| {
+                state = self.transitions[state][byte as usize] as usize;
+                self.outputs[state].len()
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matches(automaton: &AhoCorasick, haystack: &str) -> Vec<(usize, usize, usize)> {
+        automaton
+            .find_iter(haystack)
+            .map(|m| (m.pattern, m.start, m.end))
+            .collect()
+    }
+
+    #[test]
+    fn finds_every_overlapping_pattern() {
+        let automaton = AhoCorasick::new(["he", "she", "his", "hers"]);
+        assert_eq!(automaton.pattern_count(), 4);
+        // "she" and "he" end at the same position, "hers" starts inside "she"
+        assert_eq!(
+            matches(&automaton, "ushers"),
+            [(1, 1, 4), (0, 2, 4), (3, 2, 6)]
+        );
+        assert_eq!(matches(&automaton, "this"), [(2, 1, 4)]);
+        assert!(matches(&automaton, "sh e").is_empty());
+    }
+
+    #[test]
+    fn pattern_inside_another() {
+        let automaton = AhoCorasick::new(["a", "aa"]);
+        // At the same end the longer match comes first
+        let found = matches(&automaton, "aaa");
+        assert_eq!(
+            found,
+            [(0, 0, 1), (1, 0, 2), (0, 1, 2), (1, 1, 3), (0, 2, 3)]
+        );
+    }
+
+    #[test]
+    fn count_agrees_with_find_iter() {
+        let automaton = AhoCorasick::new(["XMAS", "SAMX", "MAS", "A"]);
+        for haystack in ["", "XMASAMX", "XMASXMASXMAS", "SAMXMASAMXAAA", "xmas"] {
+            assert_eq!(
+                automaton.count(haystack),
+                automaton.find_iter(haystack).count()
+            );
+        }
+        // XMAS, MAS, SAMX and both As
+        assert_eq!(automaton.count("XMASAMX"), 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "pattern 1 is empty")]
+    fn rejects_empty_patterns() {
+        AhoCorasick::new(["a", ""]);
+    }
+}
@@ -0,0 +1,275 @@
+// Sets of grid positions stored as one bit per cell, for visited tracking in
+// simulations where hashing every state would dominate the runtime.
+// `BitGrid3` adds a small third dimension, e.g. the direction of travel.
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    width: usize,
+    height: usize,
+    bits: BitSet,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BitGrid3 {
+    width: usize,
+    height: usize,
+    depth: usize,
+    bits: BitSet,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct BitSet {
+    words: Vec<u64>,
+}
+
+impl BitSet {
+    fn new(len: usize) -> Self {
+        BitSet {
+            words: vec![0; len.div_ceil(64)],
+        }
+    }
+
+    fn contains(&self, index: usize) -> bool {
+        self.words[index / 64] & (1 << (index % 64)) != 0
+    }
+
+    // Returns true if the bit was not set before
+    fn insert(&mut self, index: usize) -> bool {
+        let word = &mut self.words[index / 64];
+        let mask = 1 << (index % 64);
+        let inserted = *word & mask == 0;
+        *word |= mask;
+        inserted
+    }
+
+    // Returns true if the bit was set before
+    fn remove(&mut self, index: usize) -> bool {
+        let word = &mut self.words[index / 64];
+        let mask = 1 << (index % 64);
+        let removed = *word & mask != 0;
+        *word &= !mask;
+        removed
+    }
+
+    fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    fn len(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(i, &word)| {
+            let mut rest = word;
+            std::iter::from_fn(move || {
+                if rest == 0 {
+                    return None;
+                }
+                let bit = rest.trailing_zeros() as usize;
+                rest &= rest - 1;
+                Some(i * 64 + bit)
+            })
+        })
+    }
+}
+
+impl BitGrid {
+    pub fn new(width: usize, height: usize) -> Self {
+        BitGrid {
+            width,
+            height,
+            bits: BitSet::new(width * height),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index(&self, x: i64, y: i64) -> Option<usize> {
+        let inside = x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height;
+        inside.then(|| y as usize * self.width + x as usize)
+    }
+
+    // Positions outside the grid are never contained
+    pub fn contains(&self, x: i64, y: i64) -> bool {
+        self.index(x, y).is_some_and(|i| self.bits.contains(i))
+    }
+
+    // Returns true if the position was not in the set yet, like `HashSet::insert`
+    pub fn insert(&mut self, x: i64, y: i64) -> bool {
+        let index = self
+            .index(x, y)
+            .unwrap_or_else(|| panic!("({}, {}) is outside the grid", x, y));
+        self.bits.insert(index)
+    }
+
+    pub fn remove(&mut self, x: i64, y: i64) -> bool {
+        self.index(x, y).is_some_and(|i| self.bits.remove(i))
+    }
+
+    // Keeps the allocation, so one grid can be reused across simulations
+    pub fn clear(&mut self) {
+        self.bits.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.bits.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (i64, i64)> + '_ {
+        let width = self.width;
+        self.bits
+            .iter()
+            .map(move |i| ((i % width) as i64, (i / width) as i64))
+    }
+}
+
+impl BitGrid3 {
+    pub fn new(width: usize, height: usize, depth: usize) -> Self {
+        BitGrid3 {
+            width,
+            height,
+            depth,
+            bits: BitSet::new(width * height * depth),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    fn index(&self, x: i64, y: i64, z: usize) -> Option<usize> {
+        let inside = x >= 0
+            && y >= 0
+            && (x as usize) < self.width
+            && (y as usize) < self.height
+            && z < self.depth;
+        inside.then(|| (y as usize * self.width + x as usize) * self.depth + z)
+    }
+
+    pub fn contains(&self, x: i64, y: i64, z: usize) -> bool {
+        self.index(x, y, z).is_some_and(|i| self.bits.contains(i))
+    }
+
+    pub fn insert(&mut self, x: i64, y: i64, z: usize) -> bool {
+        let index = self
+            .index(x, y, z)
+            .unwrap_or_else(|| panic!("({}, {}, {}) is outside the grid", x, y, z));
+        self.bits.insert(index)
+    }
+
+    pub fn remove(&mut self, x: i64, y: i64, z: usize) -> bool {
+        self.index(x, y, z).is_some_and(|i| self.bits.remove(i))
+    }
+
+    pub fn clear(&mut self) {
+        self.bits.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.bits.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (i64, i64, usize)> + '_ {
+        let (width, depth) = (self.width, self.depth);
+        self.bits.iter().map(move |i| {
+            let cell = i / depth;
+            ((cell % width) as i64, (cell / width) as i64, i % depth)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bitgrid_insert_remove_and_bounds() {
+        // 13 x 11 spans several words, with a partial last one
+        let mut grid = BitGrid::new(13, 11);
+        assert!(grid.insert(0, 0));
+        assert!(grid.insert(12, 10));
+        assert!(grid.insert(5, 4));
+        assert!(!grid.insert(5, 4));
+        assert!(grid.contains(12, 10) && !grid.contains(4, 5));
+        assert_eq!(grid.len(), 3);
+        assert_eq!(grid.iter().collect::<Vec<_>>(), [(0, 0), (5, 4), (12, 10)]);
+
+        // Outside positions are never contained and cannot be removed
+        assert!(!grid.contains(-1, 0) && !grid.contains(13, 0) && !grid.contains(0, 11));
+        assert!(!grid.remove(13, 0));
+        assert!(grid.remove(5, 4));
+        assert!(!grid.remove(5, 4));
+        assert_eq!(grid.len(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "(13, 0) is outside the grid")]
+    fn bitgrid_insert_outside_panics() {
+        BitGrid::new(13, 11).insert(13, 0);
+    }
+
+    #[test]
+    fn bitgrid3_keeps_layers_apart() {
+        let mut grid = BitGrid3::new(3, 2, 4);
+        assert!(grid.insert(2, 1, 3));
+        assert!(grid.insert(2, 1, 0));
+        assert!(!grid.insert(2, 1, 3));
+        assert!(!grid.contains(2, 1, 1));
+        assert!(!grid.contains(2, 1, 4) && !grid.contains(3, 0, 0) && !grid.contains(0, -1, 0));
+        assert_eq!(grid.iter().collect::<Vec<_>>(), [(2, 1, 0), (2, 1, 3)]);
+
+        assert!(grid.remove(2, 1, 0));
+        assert!(!grid.remove(2, 1, 4));
+        assert_eq!(grid.len(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "(0, 0, 4) is outside the grid")]
+    fn bitgrid3_insert_outside_depth_panics() {
+        BitGrid3::new(3, 2, 4).insert(0, 0, 4);
+    }
+
+    #[test]
+    fn clear_allows_reuse() {
+        let mut grid = BitGrid3::new(10, 10, 4);
+        for i in 0..10 {
+            grid.insert(i, 9 - i, i as usize % 4);
+        }
+        assert_eq!(grid.len(), 10);
+        grid.clear();
+        assert!(grid.is_empty());
+        assert_eq!((grid.width(), grid.height(), grid.depth()), (10, 10, 4));
+        assert!(grid.insert(9, 0, 1));
+
+        let mut flat = BitGrid::new(4, 4);
+        flat.insert(3, 3);
+        flat.clear();
+        assert!(flat.is_empty() && !flat.contains(3, 3));
+    }
+}
@@ -0,0 +1,170 @@
+// Multiset that tallies how often each value occurs.
+
+use std::cmp::Reverse;
+use std::collections::hash_map;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Counter<T: Eq + Hash> {
+    counts: HashMap<T, usize>,
+}
+
+impl<T: Eq + Hash> Default for Counter<T> {
+    fn default() -> Self {
+        Counter {
+            counts: HashMap::new(),
+        }
+    }
+}
+
+impl<T: Eq + Hash> Counter<T> {
+    pub fn new() -> Self {
+        Counter::default()
+    }
+
+    // Counts given per value, repeated values add up
+    pub fn from_counts<I: IntoIterator<Item = (T, usize)>>(counts: I) -> Self {
+        let mut counter = Counter::new();
+        for (item, n) in counts {
+            counter.add_n(item, n);
+        }
+        counter
+    }
+
+    pub fn add(&mut self, item: T) {
+        self.add_n(item, 1);
+    }
+
+    pub fn add_n(&mut self, item: T, n: usize) {
+        if n > 0 {
+            *self.counts.entry(item).or_insert(0) += n;
+        }
+    }
+
+    // 0 for values that were never added
+    pub fn count(&self, item: &T) -> usize {
+        self.counts.get(item).copied().unwrap_or(0)
+    }
+
+    // Number of distinct values
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    // Sum of all counts
+    pub fn total(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&T, usize)> {
+        self.counts.iter().map(|(item, &count)| (item, count))
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &T> {
+        self.counts.keys()
+    }
+
+    // The `n` most frequent values, highest count first. Values with equal
+    // counts come in no particular order.
+    pub fn most_common(&self, n: usize) -> Vec<(&T, usize)> {
+        let mut entries = self.iter().collect::<Vec<_>>();
+        entries.sort_unstable_by_key(|&(_, count)| Reverse(count));
+        entries.truncate(n);
+        entries
+    }
+}
+
+impl<T: Eq + Hash + Clone> Counter<T> {
+    // Highest count of each value in either counter
+    pub fn union(&self, other: &Counter<T>) -> Counter<T> {
+        let mut result = self.clone();
+        for (item, count) in other.iter() {
+            let entry = result.counts.entry(item.clone()).or_insert(0);
+            *entry = (*entry).max(count);
+        }
+        result
+    }
+
+    // Lowest count of each value present in both counters
+    pub fn intersection(&self, other: &Counter<T>) -> Counter<T> {
+        Counter::from_counts(self.iter().filter_map(|(item, count)| {
+            let common = count.min(other.count(item));
+            (common > 0).then(|| (item.clone(), common))
+        }))
+    }
+}
+
+impl<T: Eq + Hash> FromIterator<T> for Counter<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut counter = Counter::new();
+        counter.extend(iter);
+        counter
+    }
+}
+
+impl<T: Eq + Hash> Extend<T> for Counter<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.add(item);
+        }
+    }
+}
+
+impl<T: Eq + Hash> IntoIterator for Counter<T> {
+    type Item = (T, usize);
+    type IntoIter = hash_map::IntoIter<T, usize>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.counts.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_values() {
+        let mut counter: Counter<char> = "abracadabra".chars().collect();
+        assert_eq!(counter.count(&'a'), 5);
+        assert_eq!(counter.count(&'z'), 0);
+        assert_eq!((counter.len(), counter.total()), (5, 11));
+
+        counter.add_n('z', 0);
+        counter.extend(['z', 'z']);
+        assert_eq!(counter.count(&'z'), 2);
+        assert_eq!(counter.len(), 6);
+        assert_eq!(counter.most_common(1), [(&'a', 5)]);
+        // b, r and z tie, only the counts are ordered
+        let counts = counter.most_common(3).into_iter().map(|(_, n)| n);
+        assert_eq!(counts.collect::<Vec<_>>(), [5, 2, 2]);
+        assert_eq!(counter.most_common(10).len(), 6);
+    }
+
+    #[test]
+    fn from_counts_adds_repeated_values() {
+        let counter = Counter::from_counts([("x", 2), ("y", 0), ("x", 3)]);
+        assert_eq!(counter.count(&"x"), 5);
+        assert!(!counter.keys().any(|&key| key == "y"));
+        assert_eq!(counter.len(), 1);
+        assert!(Counter::<u8>::from_counts([]).is_empty());
+    }
+
+    #[test]
+    fn union_and_intersection() {
+        let a = Counter::from_counts([(1, 3), (2, 1)]);
+        let b = Counter::from_counts([(1, 1), (3, 2)]);
+        assert_eq!(a.union(&b), Counter::from_counts([(1, 3), (2, 1), (3, 2)]));
+        assert_eq!(a.intersection(&b), Counter::from_counts([(1, 1)]));
+        assert!(a.intersection(&Counter::new()).is_empty());
+
+        let mut pairs = a.into_iter().collect::<Vec<_>>();
+        pairs.sort();
+        assert_eq!(pairs, [(1, 3), (2, 1)]);
+    }
+}
@@ -0,0 +1,200 @@
+// Cycle detection for deterministic walks `x0, f(x0), f(f(x0)), ...` where
+// the step function returns None once the walk ends (e.g. leaves the map).
+// Brent and Floyd only need equality and constant memory, the hashing
+// variant trades memory for visiting every state just once.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// The walk enters the cycle after `start` steps and repeats every `length` steps
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+pub fn brent<S, F>(initial: S, mut step: F) -> Option<Cycle>
+where
+    S: Clone + PartialEq,
+    F: FnMut(&S) -> Option<S>,
+{
+    // Find the length: the tortoise teleports to the hare at every power of two
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial)?;
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare)?;
+        length += 1;
+    }
+
+    // Find the start: move a second pointer `length` ahead, then walk both
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..length {
+        hare = step(&hare)?;
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise)?;
+        hare = step(&hare)?;
+        start += 1;
+    }
+    Some(Cycle { start, length })
+}
+
+pub fn floyd<S, F>(initial: S, mut step: F) -> Option<Cycle>
+where
+    S: Clone + PartialEq,
+    F: FnMut(&S) -> Option<S>,
+{
+    let mut tortoise = step(&initial)?;
+    let mut hare = step(&tortoise)?;
+    while tortoise != hare {
+        tortoise = step(&tortoise)?;
+        hare = step(&hare)?;
+        hare = step(&hare)?;
+    }
+
+    let mut start = 0;
+    let mut tortoise = initial;
+    while tortoise != hare {
+        tortoise = step(&tortoise)?;
+        hare = step(&hare)?;
+        start += 1;
+    }
+
+    let mut length = 1;
+    let mut hare = step(&tortoise)?;
+    while tortoise != hare {
+        hare = step(&hare)?;
+        length += 1;
+    }
+    Some(Cycle { start, length })
+}
+
+pub fn find_cycle_hashing<S, F>(initial: S, mut step: F) -> Option<Cycle>
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> Option<S>,
+{
+    let mut seen = HashMap::new();
+    let mut state = initial;
+    for index in 0.. {
+        if let Some(&start) = seen.get(&state) {
+            return Some(Cycle {
+                start,
+                length: index - start,
+            });
+        }
+        let next = step(&state)?;
+        seen.insert(state, index);
+        state = next;
+    }
+    unreachable!()
+}
+
+// The state after `steps` steps, jumping ahead once the walk repeats so huge
+// step counts are cheap. None if the walk ends before that.
+pub fn state_after<S, F>(initial: S, mut step: F, steps: usize) -> Option<S>
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> Option<S>,
+{
+    let mut seen: HashMap<S, usize> = HashMap::new();
+    let mut history: Vec<S> = vec![];
+    let mut state = initial;
+    for index in 0..steps {
+        if let Some(&start) = seen.get(&state) {
+            let length = index - start;
+            return Some(history[start + (steps - start) % length].clone());
+        }
+        let next = step(&state)?;
+        seen.insert(state.clone(), index);
+        history.push(state);
+        state = next;
+    }
+    Some(state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0, 1, 2, ... wrapping from `end - 1` back to `start`
+    fn looping(start: u64, end: u64) -> impl FnMut(&u64) -> Option<u64> + Clone {
+        move |&x| Some(if x + 1 == end { start } else { x + 1 })
+    }
+
+    fn all_detectors(
+        initial: u64,
+        step: impl FnMut(&u64) -> Option<u64> + Clone,
+    ) -> [Option<Cycle>; 3] {
+        [
+            brent(initial, step.clone()),
+            floyd(initial, step.clone()),
+            find_cycle_hashing(initial, step),
+        ]
+    }
+
+    #[test]
+    fn walk_that_ends() {
+        let step = |&x: &u64| (x < 10).then_some(x + 1);
+        assert_eq!(all_detectors(0, step), [None; 3]);
+        assert_eq!(state_after(0, step, 7), Some(7));
+        assert_eq!(state_after(0, step, 10), Some(10));
+        assert_eq!(state_after(0, step, 11), None);
+    }
+
+    #[test]
+    fn self_loop_at_the_first_state() {
+        let step = |&x: &u64| Some(x);
+        let expected = Some(Cycle {
+            start: 0,
+            length: 1,
+        });
+        assert_eq!(all_detectors(5, step), [expected; 3]);
+        assert_eq!(state_after(5, step, 1_000_000), Some(5));
+    }
+
+    #[test]
+    fn tail_then_cycle() {
+        // 0..3 is the tail, then 3, 4, ..., 9 repeats
+        let step = looping(3, 10);
+        let expected = Some(Cycle {
+            start: 3,
+            length: 7,
+        });
+        assert_eq!(all_detectors(0, step), [expected; 3]);
+
+        // Starting inside the cycle leaves no tail
+        let expected = Some(Cycle {
+            start: 0,
+            length: 7,
+        });
+        assert_eq!(all_detectors(6, looping(3, 10)), [expected; 3]);
+    }
+
+    #[test]
+    fn state_after_far_beyond_the_cycle() {
+        let steps = 1_000_000_000_000;
+        let expected = 3 + (steps - 3) % 7;
+        assert_eq!(
+            state_after(0, looping(3, 10), steps as usize),
+            Some(expected)
+        );
+
+        // Every small step count against the plain walk
+        let mut step = looping(3, 10);
+        let mut state = 0;
+        for n in 0..50 {
+            assert_eq!(state_after(0, looping(3, 10), n), Some(state));
+            state = step(&state).unwrap();
+        }
+    }
+}
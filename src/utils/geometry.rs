@@ -0,0 +1,141 @@
+// Geometry on integer lattice points: lines, reflections and distances.
+
+use crate::utils::math::gcd;
+use crate::utils::point::{Point, Vector};
+
+// Axis-aligned rectangle, both corners inclusive
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rect {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Rect {
+    pub fn new(min: Point, max: Point) -> Self {
+        Rect { min, max }
+    }
+
+    // The cells of a width x height grid, starting at the origin
+    pub fn from_size(width: usize, height: usize) -> Self {
+        Rect::new(
+            Point::ORIGIN,
+            Point::new(width as i64 - 1, height as i64 - 1),
+        )
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+}
+
+// The smallest step in the direction of `v` that lands on lattice points,
+// e.g. <4, -6> -> <2, -3>. The zero vector stays zero.
+pub fn reduce(v: Vector) -> Vector {
+    let divisor = gcd(v.x.unsigned_abs(), v.y.unsigned_abs()) as i64;
+    if divisor == 0 {
+        return v;
+    }
+    Vector::new(v.x / divisor, v.y / divisor)
+}
+
+// Every lattice point on the infinite line through `a` and `b` that lies
+// inside `bounds`, ordered from the `a` side to the `b` side
+pub fn line_points(a: Point, b: Point, bounds: Rect) -> Vec<Point> {
+    let step = reduce(b - a);
+    if step == Vector::ZERO {
+        return if bounds.contains(a) { vec![a] } else { vec![] };
+    }
+
+    // The points are a + t * step, each axis limits the range of t
+    let x_range = step_range(a.x, step.x, bounds.min.x, bounds.max.x);
+    let y_range = step_range(a.y, step.y, bounds.min.y, bounds.max.y);
+    let (Some((x_from, x_to)), Some((y_from, y_to))) = (x_range, y_range) else {
+        return vec![];
+    };
+    (x_from.max(y_from)..=x_to.min(y_to))
+        .map(|t| a + step * t)
+        .collect()
+}
+
+// Range of t with min <= start + t * step <= max
+fn step_range(start: i64, step: i64, min: i64, max: i64) -> Option<(i64, i64)> {
+    if step == 0 {
+        return (min..=max).contains(&start).then_some((i64::MIN, i64::MAX));
+    }
+    let (low, high) = if step > 0 {
+        (min - start, max - start)
+    } else {
+        (start - max, start - min)
+    };
+    let step = step.abs();
+    Some((-(-low).div_euclid(step), high.div_euclid(step)))
+}
+
+// Mirror image of `point` with `center` in the middle
+pub fn reflect(point: Point, center: Point) -> Point {
+    center + (center - point)
+}
+
+pub fn collinear(a: Point, b: Point, c: Point) -> bool {
+    let (ab, ac) = (b - a, c - a);
+    ab.x * ac.y == ab.y * ac.x
+}
+
+pub fn manhattan(a: Point, b: Point) -> i64 {
+    (a.x - b.x).abs() + (a.y - b.y).abs()
+}
+
+pub fn chebyshev(a: Point, b: Point) -> i64 {
+    (a.x - b.x).abs().max((a.y - b.y).abs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn points(coords: &[(i64, i64)]) -> Vec<Point> {
+        coords.iter().map(|&pos| Point::from(pos)).collect()
+    }
+
+    #[test]
+    fn line_leaves_the_grid_in_both_directions() {
+        let bounds = Rect::from_size(5, 5);
+        let line = line_points(Point::new(2, 2), Point::new(3, 3), bounds);
+        assert_eq!(line, points(&[(0, 0), (1, 1), (2, 2), (3, 3), (4, 4)]));
+
+        // Reduced step, going up and to the right from a on the left edge
+        let line = line_points(Point::new(0, 4), Point::new(4, 2), bounds);
+        assert_eq!(line, points(&[(0, 4), (2, 3), (4, 2)]));
+
+        // Both points outside, the line still crosses the grid
+        let line = line_points(Point::new(1, 9), Point::new(1, 7), bounds);
+        assert_eq!(line, points(&[(1, 4), (1, 3), (1, 2), (1, 1), (1, 0)]));
+
+        // Passing beside the grid, once parallel to an edge and once diagonally
+        let line = line_points(Point::new(-1, 7), Point::new(-1, 6), bounds);
+        assert!(line.is_empty());
+        let line = line_points(Point::new(5, 0), Point::new(6, 1), bounds);
+        assert!(line.is_empty());
+    }
+
+    #[test]
+    fn zero_step_is_a_single_point() {
+        let bounds = Rect::from_size(3, 3);
+        let inside = Point::new(1, 2);
+        assert_eq!(line_points(inside, inside, bounds), [inside]);
+        let outside = Point::new(3, 0);
+        assert!(line_points(outside, outside, bounds).is_empty());
+        assert_eq!(reduce(Vector::ZERO), Vector::ZERO);
+        assert_eq!(reduce(Vector::new(4, -6)), Vector::new(2, -3));
+    }
+
+    #[test]
+    fn reflections_and_distances() {
+        let (a, b) = (Point::new(1, 2), Point::new(3, 3));
+        assert_eq!(reflect(a, b), Point::new(5, 4));
+        assert_eq!(reflect(b, b), b);
+        assert!(collinear(a, b, reflect(a, b)));
+        assert!(!collinear(a, b, Point::new(3, 4)));
+        assert_eq!((manhattan(a, b), chebyshev(a, b)), (3, 2));
+    }
+}
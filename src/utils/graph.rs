@@ -0,0 +1,236 @@
+// Directed graph over hashable node values, built from edge lists.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
+use std::hash::Hash;
+
+#[derive(Clone, Debug)]
+pub struct Graph<N> {
+    successors: HashMap<N, Vec<N>>,
+}
+
+// The nodes of a cycle in edge order, the last node leads back to the first
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cycle<N>(pub Vec<N>);
+
+impl<N: fmt::Display> fmt::Display for Cycle<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for node in &self.0 {
+            write!(f, "{} -> ", node)?;
+        }
+        match self.0.first() {
+            Some(first) => write!(f, "{}", first),
+            None => write!(f, "(empty)"),
+        }
+    }
+}
+
+impl<N: Clone + Eq + Hash> Default for Graph<N> {
+    fn default() -> Self {
+        Graph {
+            successors: HashMap::new(),
+        }
+    }
+}
+
+impl<N: Clone + Eq + Hash> Graph<N> {
+    pub fn new() -> Self {
+        Graph::default()
+    }
+
+    pub fn from_edges<I>(edges: I) -> Self
+    where
+        I: IntoIterator<Item = (N, N)>,
+    {
+        let mut graph = Graph::new();
+        for (from, to) in edges {
+            graph.add_edge(from, to);
+        }
+        graph
+    }
+
+    pub fn add_node(&mut self, node: N) {
+        self.successors.entry(node).or_default();
+    }
+
+    pub fn add_edge(&mut self, from: N, to: N) {
+        self.add_node(to.clone());
+        self.successors.entry(from).or_default().push(to);
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = &N> {
+        self.successors.keys()
+    }
+
+    pub fn successors(&self, node: &N) -> &[N] {
+        self.successors.get(node).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn has_edge(&self, from: &N, to: &N) -> bool {
+        self.successors(from).contains(to)
+    }
+
+    // All nodes reachable from `start`, including `start` itself
+    pub fn reachable(&self, start: &N) -> HashSet<N> {
+        let mut seen = HashSet::from([start.clone()]);
+        let mut stack = vec![start.clone()];
+        while let Some(node) = stack.pop() {
+            for next in self.successors(&node) {
+                if seen.insert(next.clone()) {
+                    stack.push(next.clone());
+                }
+            }
+        }
+        seen
+    }
+
+    pub fn is_reachable(&self, from: &N, to: &N) -> bool {
+        from == to || self.reachable(from).contains(to)
+    }
+
+    pub fn topological_sort(&self) -> Result<Vec<N>, Cycle<N>> {
+        let nodes = self.successors.keys().cloned().collect::<Vec<_>>();
+        self.topological_sort_subset(&nodes)
+    }
+
+    // Kahn's algorithm on the subgraph induced by `nodes`. Ties are broken by
+    // the order of `nodes`, so the result is deterministic.
+    pub fn topological_sort_subset(&self, nodes: &[N]) -> Result<Vec<N>, Cycle<N>> {
+        let mut subset = HashSet::new();
+        let nodes = nodes
+            .iter()
+            .filter(|node| subset.insert(*node))
+            .collect::<Vec<_>>();
+        let mut in_degree = nodes
+            .iter()
+            .map(|&node| (node, 0usize))
+            .collect::<HashMap<_, _>>();
+        for node in &nodes {
+            for next in self.successors(node) {
+                if let Some(degree) = in_degree.get_mut(next) {
+                    *degree += 1;
+                }
+            }
+        }
+
+        let mut queue = nodes
+            .iter()
+            .copied()
+            .filter(|node| in_degree[node] == 0)
+            .collect::<VecDeque<_>>();
+        let mut order = Vec::with_capacity(nodes.len());
+        while let Some(node) = queue.pop_front() {
+            order.push(node.clone());
+            for next in self.successors(node) {
+                if let Some(degree) = in_degree.get_mut(next) {
+                    *degree -= 1;
+                    if *degree == 0 {
+                        queue.push_back(next);
+                    }
+                }
+            }
+        }
+
+        if order.len() == subset.len() {
+            return Ok(order);
+        }
+        let remaining = nodes
+            .into_iter()
+            .filter(|node| in_degree[node] > 0)
+            .cloned()
+            .collect::<HashSet<_>>();
+        Err(self
+            .find_cycle_in(&remaining)
+            .expect("nodes left over by Kahn's algorithm must contain a cycle"))
+    }
+
+    pub fn find_cycle(&self) -> Option<Cycle<N>> {
+        let nodes = self.successors.keys().cloned().collect::<HashSet<_>>();
+        self.find_cycle_in(&nodes)
+    }
+
+    // Depth-first search that only follows edges between `nodes`
+    fn find_cycle_in(&self, nodes: &HashSet<N>) -> Option<Cycle<N>> {
+        let mut finished = HashSet::new();
+
+        for root in nodes {
+            if finished.contains(root) {
+                continue;
+            }
+            // The current path, each node with the index of its next successor
+            let mut path: Vec<(N, usize)> = vec![(root.clone(), 0)];
+            let mut on_path = HashSet::from([root.clone()]);
+
+            while let Some((node, index)) = path.last_mut() {
+                let next = self.successors(node).get(*index).cloned();
+                *index += 1;
+                match next {
+                    Some(next) if !nodes.contains(&next) || finished.contains(&next) => {}
+                    Some(next) if on_path.contains(&next) => {
+                        let start = path.iter().position(|(n, _)| *n == next).unwrap();
+                        return Some(Cycle(path.drain(start..).map(|(n, _)| n).collect()));
+                    }
+                    Some(next) => {
+                        on_path.insert(next.clone());
+                        path.push((next, 0));
+                    }
+                    None => {
+                        let (node, _) = path.pop().unwrap();
+                        on_path.remove(&node);
+                        finished.insert(node);
+                    }
+                }
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Every node of the cycle leads to the next one, the last back to the first
+    fn is_cycle_of(graph: &Graph<u32>, cycle: &Cycle<u32>) -> bool {
+        let nodes = &cycle.0;
+        !nodes.is_empty()
+            && (0..nodes.len()).all(|i| graph.has_edge(&nodes[i], &nodes[(i + 1) % nodes.len()]))
+    }
+
+    #[test]
+    fn subset_order_ignores_outside_nodes() {
+        // 1 -> 5 -> 3 goes through a node outside the subset, so it does not count
+        let graph = Graph::from_edges([(1, 5), (5, 3), (4, 1), (4, 3), (3, 2)]);
+        assert_eq!(
+            graph.topological_sort_subset(&[3, 1, 2, 4]),
+            Ok(vec![4, 1, 3, 2])
+        );
+        assert_eq!(graph.topological_sort_subset(&[2, 3, 2]), Ok(vec![3, 2]));
+        assert_eq!(graph.topological_sort_subset(&[]), Ok(vec![]));
+    }
+
+    #[test]
+    fn returns_the_cycle_path() {
+        let graph = Graph::from_edges([(1, 2), (2, 3), (3, 4), (4, 2), (4, 5)]);
+        let cycle = graph.topological_sort().unwrap_err();
+        assert_eq!(cycle.0.len(), 3);
+        assert!(is_cycle_of(&graph, &cycle));
+        assert!(is_cycle_of(&graph, &graph.find_cycle().unwrap()));
+
+        // Dropping 3 from the subset breaks the cycle
+        assert_eq!(graph.topological_sort_subset(&[4, 2, 1]), Ok(vec![4, 1, 2]));
+        let self_loop = Graph::from_edges([(7, 7)]);
+        assert_eq!(self_loop.find_cycle(), Some(Cycle(vec![7])));
+        assert_eq!(Cycle(vec![2, 3, 4]).to_string(), "2 -> 3 -> 4 -> 2");
+    }
+
+    #[test]
+    fn acyclic_graph_has_no_cycle() {
+        // Diamond, 4 is reached twice without a cycle
+        let graph = Graph::from_edges([(1, 2), (1, 3), (2, 4), (3, 4)]);
+        assert_eq!(graph.find_cycle(), None);
+        assert_eq!(graph.find_cycle_in(&HashSet::from([1, 2, 4])), None);
+        let order = graph.topological_sort().unwrap();
+        assert_eq!((order[0], order[3]), (1, 4));
+    }
+}
@@ -0,0 +1,355 @@
+// Dense rectangular grid, as used by the character-map days.
+// Coordinates are signed (x = column, y = row) so neighbours can be probed
+// without casting; anything outside the grid simply yields `None`.
+
+use std::fmt;
+
+use crate::utils::geometry::Rect;
+use crate::utils::point::{Direction, Point};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "Grid rows must all have the same length"
+        );
+        Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    pub fn parse_with<F>(input: &str, mut parse_cell: F) -> Self
+    where
+        F: FnMut(char) -> T,
+    {
+        Grid::from_rows(
+            input
+                .lines()
+                .map(|line| line.chars().map(&mut parse_cell).collect())
+                .collect(),
+        )
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn bounds(&self) -> Rect {
+        Rect::from_size(self.width, self.height)
+    }
+
+    pub fn contains(&self, x: i64, y: i64) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+
+    pub fn contains_point(&self, point: Point) -> bool {
+        self.contains(point.x, point.y)
+    }
+
+    fn index(&self, x: i64, y: i64) -> Option<usize> {
+        self.contains(x, y)
+            .then(|| y as usize * self.width + x as usize)
+    }
+
+    pub fn get(&self, x: i64, y: i64) -> Option<&T> {
+        self.index(x, y).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, x: i64, y: i64) -> Option<&mut T> {
+        self.index(x, y).map(|i| &mut self.cells[i])
+    }
+
+    // Returns false if the position is outside the grid
+    pub fn set(&mut self, x: i64, y: i64, value: T) -> bool {
+        match self.get_mut(x, y) {
+            Some(cell) => {
+                *cell = value;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn get_point(&self, point: Point) -> Option<&T> {
+        self.get(point.x, point.y)
+    }
+
+    pub fn get_point_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.get_mut(point.x, point.y)
+    }
+
+    pub fn set_point(&mut self, point: Point, value: T) -> bool {
+        self.set(point.x, point.y, value)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    // Walks from (x, y) in steps of (dx, dy) until leaving the grid
+    pub fn ray(&self, x: i64, y: i64, dx: i64, dy: i64) -> impl Iterator<Item = &T> {
+        self.walk(x, y, dx, dy).map(|(_, cell)| cell)
+    }
+
+    // Like `ray`, with the coordinates of every cell
+    pub fn walk(&self, x: i64, y: i64, dx: i64, dy: i64) -> impl Iterator<Item = ((i64, i64), &T)> {
+        (0..)
+            .map(move |step| (x + step * dx, y + step * dy))
+            .map_while(|(x, y)| self.get(x, y).map(|cell| ((x, y), cell)))
+    }
+
+    // Down-right from (x, y)
+    pub fn diagonal(&self, x: i64, y: i64) -> impl Iterator<Item = &T> {
+        self.ray(x, y, 1, 1)
+    }
+
+    // Down-left from (x, y)
+    pub fn anti_diagonal(&self, x: i64, y: i64) -> impl Iterator<Item = &T> {
+        self.ray(x, y, -1, 1)
+    }
+
+    // All down-right diagonals, from the bottom-left corner to the top-right one
+    pub fn diagonals(&self) -> impl Iterator<Item = Vec<((i64, i64), &T)>> {
+        let (width, height) = (self.width as i64, self.height as i64);
+        (1..height)
+            .rev()
+            .map(|y| (0, y))
+            .chain((0..width).map(|x| (x, 0)))
+            .map(move |(x, y)| self.walk(x, y, 1, 1).collect())
+    }
+
+    // All down-left anti-diagonals, from the top-left corner to the bottom-right one
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = Vec<((i64, i64), &T)>> {
+        let (width, height) = (self.width as i64, self.height as i64);
+        (0..width)
+            .map(|x| (x, 0))
+            .chain((1..height).map(move |y| (width - 1, y)))
+            .map(move |(x, y)| self.walk(x, y, -1, 1).collect())
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = (i64, i64)> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| ((i % width) as i64, (i / width) as i64))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = ((i64, i64), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    // `positions` and `iter` with `Point`s instead of tuples
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        self.positions().map(Point::from)
+    }
+
+    pub fn iter_points(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn find(&self, value: &T) -> Option<(i64, i64)>
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .find(|(_, cell)| *cell == value)
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = (i64, i64)> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(pos, _)| pos)
+    }
+
+    // Up, right, down and left of `point`, as far as they are inside the grid
+    pub fn neighbors4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ORTHOGONAL
+            .into_iter()
+            .map(move |direction| point + direction)
+            .filter(|&p| self.contains_point(p))
+    }
+
+    // Including diagonals
+    pub fn neighbors8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL
+            .into_iter()
+            .map(move |direction| point + direction)
+            .filter(|&p| self.contains_point(p))
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+// Transformations, all of which work on non-square grids
+impl<T: Clone> Grid<T> {
+    fn transformed<F>(&self, width: usize, height: usize, source: F) -> Grid<T>
+    where
+        F: Fn(usize, usize) -> (usize, usize),
+    {
+        let cells = (0..width * height)
+            .map(|i| {
+                let (x, y) = source(i % width, i / width);
+                self.cells[y * self.width + x].clone()
+            })
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    // Rows become columns
+    pub fn transpose(&self) -> Grid<T> {
+        self.transformed(self.height, self.width, |x, y| (y, x))
+    }
+
+    // Clockwise
+    pub fn rotate_90(&self) -> Grid<T> {
+        let height = self.height;
+        self.transformed(self.height, self.width, |x, y| (y, height - 1 - x))
+    }
+
+    pub fn rotate_180(&self) -> Grid<T> {
+        let (width, height) = (self.width, self.height);
+        self.transformed(width, height, |x, y| (width - 1 - x, height - 1 - y))
+    }
+
+    pub fn rotate_270(&self) -> Grid<T> {
+        let width = self.width;
+        self.transformed(self.height, self.width, |x, y| (width - 1 - y, x))
+    }
+
+    // Mirrors left and right
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        let width = self.width;
+        self.transformed(width, self.height, |x, y| (width - 1 - x, y))
+    }
+
+    // Mirrors top and bottom
+    pub fn flip_vertical(&self) -> Grid<T> {
+        let height = self.height;
+        self.transformed(self.width, height, |x, y| (x, height - 1 - y))
+    }
+}
+
+impl Grid<char> {
+    pub fn parse(input: &str) -> Self {
+        Grid::parse_with(input, |c| c)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 3 wide, 2 high
+    fn sample() -> Grid<char> {
+        Grid::parse("abc\ndef")
+    }
+
+    fn cells(line: Vec<((i64, i64), &char)>) -> String {
+        line.into_iter().map(|(_, &c)| c).collect()
+    }
+
+    #[test]
+    fn rotations_and_flips() {
+        let grid = sample();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_90().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_180().to_string(), "fed\ncba");
+        assert_eq!(grid.rotate_270().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.flip_horizontal().to_string(), "cba\nfed");
+        assert_eq!(grid.flip_vertical().to_string(), "def\nabc");
+
+        let rotated = grid.rotate_90();
+        assert_eq!((rotated.width(), rotated.height()), (2, 3));
+        assert_eq!(rotated.rotate_270(), grid);
+        assert_eq!(grid.flip_horizontal().flip_vertical(), grid.rotate_180());
+    }
+
+    #[test]
+    fn four_quarter_turns_return_the_original() {
+        let grid = sample();
+        let turned = grid.rotate_90().rotate_90().rotate_90().rotate_90();
+        assert_eq!(turned, grid);
+    }
+
+    #[test]
+    fn diagonals_cover_every_cell_once() {
+        let grid = sample();
+        let diagonals = grid.diagonals().map(cells).collect::<Vec<_>>();
+        assert_eq!(diagonals, ["d", "ae", "bf", "c"]);
+        let anti_diagonals = grid.anti_diagonals().map(cells).collect::<Vec<_>>();
+        assert_eq!(anti_diagonals, ["a", "bd", "ce", "f"]);
+
+        let main = grid.diagonals().nth(1).unwrap();
+        assert_eq!(
+            main.iter().map(|&(pos, _)| pos).collect::<Vec<_>>(),
+            [(0, 0), (1, 1)]
+        );
+    }
+}
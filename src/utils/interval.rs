@@ -0,0 +1,336 @@
+// Sets of i64 values stored as sorted, disjoint half-open ranges. Touching
+// ranges are merged, so every set has a single representation.
+
+use std::fmt;
+use std::ops::{Range, RangeInclusive};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet {
+    ranges: Vec<Range<i64>>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        IntervalSet { ranges: vec![] }
+    }
+
+    // start..end
+    pub fn half_open(start: i64, end: i64) -> Self {
+        let mut set = IntervalSet::new();
+        set.insert(start..end);
+        set
+    }
+
+    // start..=end, panics if the range is not empty and end is i64::MAX
+    pub fn inclusive(start: i64, end: i64) -> Self {
+        let mut set = IntervalSet::new();
+        set.insert_inclusive(start..=end);
+        set
+    }
+
+    pub fn insert(&mut self, range: Range<i64>) {
+        if range.is_empty() {
+            return;
+        }
+        // Ranges from i to j overlap or touch the new one
+        let i = self.ranges.partition_point(|r| r.end < range.start);
+        let j = self.ranges.partition_point(|r| r.start <= range.end);
+        let merged = if i < j {
+            range.start.min(self.ranges[i].start)..range.end.max(self.ranges[j - 1].end)
+        } else {
+            range
+        };
+        self.ranges.splice(i..j, [merged]);
+    }
+
+    // Panics like `inclusive` on a non-empty range ending at i64::MAX
+    pub fn insert_inclusive(&mut self, range: RangeInclusive<i64>) {
+        self.insert(half_open(range));
+    }
+
+    pub fn remove(&mut self, range: Range<i64>) {
+        if range.is_empty() {
+            return;
+        }
+        // Ranges from i to j overlap the removed one, only the first and
+        // last can keep a piece sticking out of it
+        let i = self.ranges.partition_point(|r| r.end <= range.start);
+        let j = self.ranges.partition_point(|r| r.start < range.end);
+        if i == j {
+            return;
+        }
+        let left = self.ranges[i].start..range.start;
+        let right = range.end..self.ranges[j - 1].end;
+        let pieces = [left, right].into_iter().filter(|r| !r.is_empty());
+        self.ranges.splice(i..j, pieces);
+    }
+
+    // i64::MAX is never in a set, so removing up to it cannot fail
+    pub fn remove_inclusive(&mut self, range: RangeInclusive<i64>) {
+        if !range.is_empty() {
+            self.remove(*range.start()..range.end().saturating_add(1));
+        }
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        let i = self.ranges.partition_point(|r| r.end <= value);
+        self.ranges.get(i).is_some_and(|r| r.start <= value)
+    }
+
+    // Whether every value of the range is in the set
+    pub fn contains_range(&self, range: Range<i64>) -> bool {
+        if range.is_empty() {
+            return true;
+        }
+        let i = self.ranges.partition_point(|r| r.end <= range.start);
+        self.ranges
+            .get(i)
+            .is_some_and(|r| r.start <= range.start && range.end <= r.end)
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        let mut result = self.clone();
+        for range in other.iter() {
+            result.insert(range);
+        }
+        result
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let overlap = a.start.max(b.start)..a.end.min(b.end);
+            if !overlap.is_empty() {
+                ranges.push(overlap);
+            }
+            // Advance whichever range ends first, the other may overlap more
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut result = self.clone();
+        for range in other.iter() {
+            result.remove(range);
+        }
+        result
+    }
+
+    // Number of values in the set
+    pub fn total_length(&self) -> u64 {
+        self.ranges.iter().map(|r| r.end.abs_diff(r.start)).sum()
+    }
+
+    // Number of disjoint ranges
+    pub fn range_count(&self) -> usize {
+        self.ranges.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn min(&self) -> Option<i64> {
+        self.ranges.first().map(|r| r.start)
+    }
+
+    pub fn max(&self) -> Option<i64> {
+        self.ranges.last().map(|r| r.end - 1)
+    }
+
+    // Disjoint ranges in increasing order
+    pub fn iter(&self) -> impl Iterator<Item = Range<i64>> + '_ {
+        self.ranges.iter().cloned()
+    }
+
+    pub fn iter_inclusive(&self) -> impl Iterator<Item = RangeInclusive<i64>> + '_ {
+        self.ranges.iter().map(|r| r.start..=r.end - 1)
+    }
+}
+
+// Ranges are stored half-open, so i64::MAX itself can never be in a set.
+// Empty ranges map to an empty range whatever their end.
+fn half_open(range: RangeInclusive<i64>) -> Range<i64> {
+    if range.is_empty() {
+        return 0..0;
+    }
+    let end = range
+        .end()
+        .checked_add(1)
+        .expect("IntervalSet cannot hold i64::MAX");
+    *range.start()..end
+}
+
+impl FromIterator<Range<i64>> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Range<i64>>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl FromIterator<RangeInclusive<i64>> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<i64>>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<Range<i64>> for IntervalSet {
+    fn extend<I: IntoIterator<Item = Range<i64>>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+impl Extend<RangeInclusive<i64>> for IntervalSet {
+    fn extend<I: IntoIterator<Item = RangeInclusive<i64>>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert_inclusive(range);
+        }
+    }
+}
+
+// Inclusive notation, e.g. "{1..=3, 7..=7}"
+impl fmt::Display for IntervalSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{")?;
+        for (i, range) in self.iter_inclusive().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}..={}", range.start(), range.end())?;
+        }
+        write!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ranges(set: &IntervalSet) -> Vec<Range<i64>> {
+        set.iter().collect()
+    }
+
+    #[test]
+    fn insert_merges_overlapping_and_touching_ranges() {
+        let mut set = IntervalSet::new();
+        set.insert(10..20);
+        set.insert(30..40);
+        set.insert(5..5);
+        assert_eq!(ranges(&set), [10..20, 30..40]);
+
+        // Touching on either side
+        set.insert(20..25);
+        set.insert(0..10);
+        assert_eq!(ranges(&set), [0..25, 30..40]);
+
+        // Already covered
+        set.insert(12..18);
+        assert_eq!(ranges(&set), [0..25, 30..40]);
+
+        // Bridging several ranges at once
+        set.insert(50..60);
+        set.insert(24..51);
+        assert_eq!(ranges(&set), vec![0..60]);
+        assert_eq!(set.total_length(), 60);
+    }
+
+    #[test]
+    fn remove_splits_ranges() {
+        let mut set: IntervalSet = [0..10, 20..30, 40..50].into_iter().collect();
+
+        // Middle of one range
+        set.remove(3..5);
+        assert_eq!(ranges(&set), [0..3, 5..10, 20..30, 40..50]);
+
+        // Across several ranges, trimming both ends
+        set.remove(8..45);
+        assert_eq!(ranges(&set), [0..3, 5..8, 45..50]);
+
+        // Whole ranges, gaps and empty ranges
+        set.remove(5..8);
+        set.remove(30..40);
+        set.remove(1..1);
+        assert_eq!(ranges(&set), [0..3, 45..50]);
+        assert_eq!(set.total_length(), 8);
+    }
+
+    #[test]
+    fn inclusive_and_half_open_constructors() {
+        assert_eq!(IntervalSet::inclusive(1, 3), IntervalSet::half_open(1, 4));
+        assert!(IntervalSet::inclusive(3, 1).is_empty());
+        assert!(IntervalSet::half_open(3, 3).is_empty());
+
+        let mut set = IntervalSet::inclusive(-5, 5);
+        set.remove_inclusive(0..=0);
+        assert_eq!(set.iter_inclusive().collect::<Vec<_>>(), [-5..=-1, 1..=5]);
+        assert_eq!((set.min(), set.max()), (Some(-5), Some(5)));
+        assert_eq!(set.to_string(), "{-5..=-1, 1..=5}");
+    }
+
+    #[test]
+    fn containment() {
+        let set: IntervalSet = [0..=4, 10..=14].into_iter().collect();
+        assert!(set.contains(0) && set.contains(4) && set.contains(10));
+        assert!(!set.contains(5) && !set.contains(-1) && !set.contains(15));
+        assert!(set.contains_range(1..4));
+        assert!(set.contains_range(7..7));
+        assert!(!set.contains_range(3..11));
+        assert!(!set.contains_range(4..6));
+    }
+
+    #[test]
+    fn set_operations() {
+        let a: IntervalSet = [0..10, 20..30].into_iter().collect();
+        let b: IntervalSet = [5..25, 28..40].into_iter().collect();
+
+        assert_eq!(ranges(&a.union(&b)), vec![0..40]);
+        assert_eq!(ranges(&a.intersection(&b)), [5..10, 20..25, 28..30]);
+        assert_eq!(ranges(&a.difference(&b)), [0..5, 25..28]);
+        assert_eq!(ranges(&b.difference(&a)), [10..20, 30..40]);
+
+        let empty = IntervalSet::new();
+        assert_eq!(a.intersection(&empty), empty);
+        assert_eq!(a.union(&empty), a);
+        assert_eq!(a.difference(&a), empty);
+    }
+
+    #[test]
+    fn extreme_values() {
+        let set = IntervalSet::inclusive(i64::MIN, i64::MAX - 1);
+        assert_eq!(set.total_length(), u64::MAX);
+        assert!(set.contains(i64::MIN) && !set.contains(i64::MAX));
+    }
+
+    #[test]
+    fn empty_and_removed_ranges_at_max() {
+        // Exhausted by iteration, so empty although it ends at i64::MAX
+        let mut last = i64::MAX..=i64::MAX;
+        last.next();
+        let mut set: IntervalSet = [last.clone(), 1..=2].into_iter().collect();
+        assert_eq!(ranges(&set), vec![1..3]);
+
+        set.insert(10..i64::MAX);
+        set.remove_inclusive(last);
+        set.remove_inclusive(20..=i64::MAX);
+        assert_eq!(ranges(&set), [1..3, 10..20]);
+        set.remove_inclusive(i64::MIN..=i64::MAX);
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot hold i64::MAX")]
+    fn inclusive_end_at_max_panics() {
+        IntervalSet::inclusive(0, i64::MAX);
+    }
+}
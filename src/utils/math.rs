@@ -0,0 +1,206 @@
+// Number theory and decimal digit helpers.
+
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+// 0 if either argument is 0
+pub fn lcm(a: u64, b: u64) -> u64 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+    a / gcd(a, b) * b
+}
+
+// (g, x, y) with a * x + b * y = g = gcd(a, b)
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (g, x, y) = extended_gcd_i128(a as i128, b as i128);
+    (g as i64, x as i64, y as i64)
+}
+
+fn extended_gcd_i128(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+// x with a * x = 1 (mod m), if a and m are coprime
+pub fn mod_inverse(a: i64, m: i64) -> Option<i64> {
+    let (g, x, _) = extended_gcd(a.rem_euclid(m), m);
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+// Solves x = r (mod m) for all (r, m) pairs. The moduli need not be coprime
+// but must be positive. Returns (x, lcm of the moduli) with 0 <= x < lcm, or
+// None if there is no solution. The lcm of a few i64 moduli quickly outgrows
+// i64, so everything stays in i128; panics if even that overflows.
+pub fn crt(congruences: &[(i64, i64)]) -> Option<(i128, i128)> {
+    let mut x: i128 = 0;
+    let mut modulus: i128 = 1;
+    for &(residue, m) in congruences {
+        assert!(m > 0, "CRT modulus must be positive, got {}", m);
+        let (residue, m) = (residue as i128, m as i128);
+        let (g, p, _) = extended_gcd_i128(modulus, m);
+        let diff = residue - x;
+        if diff % g != 0 {
+            return None;
+        }
+        let step = m / g;
+        // Both factors are below 2^63, so the product fits
+        let t = (diff / g % step * (p % step)).rem_euclid(step);
+        let next = modulus
+            .checked_mul(step)
+            .expect("lcm of the CRT moduli overflows i128");
+        x = (x + modulus * t).rem_euclid(next);
+        modulus = next;
+    }
+    Some((x, modulus))
+}
+
+// Number of decimal digits, 0 has one digit
+pub fn digit_count(n: u64) -> u32 {
+    n.checked_ilog10().map_or(1, |log| log + 1)
+}
+
+pub fn pow10(exp: u32) -> Option<u64> {
+    10u64.checked_pow(exp)
+}
+
+// The digits of `a` followed by the digits of `b`, e.g. (12, 345) -> 12345
+pub fn concat_digits(a: u64, b: u64) -> Option<u64> {
+    a.checked_mul(pow10(digit_count(b))?)?.checked_add(b)
+}
+
+// Inverse of `concat_digits`: the number left after removing `suffix` from
+// the end of `n`, e.g. (12345, 45) -> 123
+pub fn strip_digits(n: u64, suffix: u64) -> Option<u64> {
+    let shift = pow10(digit_count(suffix))?;
+    (n % shift == suffix).then_some(n / shift)
+}
+
+// Largest r with r * r <= n
+pub fn isqrt(n: u64) -> u64 {
+    let mut r = (n as f64).sqrt() as u64;
+    while r.checked_mul(r).is_none_or(|square| square > n) {
+        r -= 1;
+    }
+    while (r + 1).checked_mul(r + 1).is_some_and(|square| square <= n) {
+        r += 1;
+    }
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gcd_and_lcm() {
+        assert_eq!(gcd(48, 18), 6);
+        assert_eq!((gcd(0, 7), gcd(7, 0), gcd(0, 0)), (7, 7, 0));
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!((lcm(0, 5), lcm(5, 0)), (0, 0));
+        assert_eq!(lcm(u64::MAX, u64::MAX), u64::MAX);
+    }
+
+    #[test]
+    fn extended_gcd_satisfies_bezout() {
+        for (a, b) in [
+            (240, 46),
+            (46, 240),
+            (-12, 18),
+            (7, 0),
+            (0, 0),
+            (i64::MAX, 2),
+        ] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(g, gcd(a.unsigned_abs(), b.unsigned_abs()) as i64);
+            assert_eq!(a as i128 * x as i128 + b as i128 * y as i128, g as i128);
+        }
+    }
+
+    #[test]
+    fn mod_inverse_exists_only_for_coprime_values() {
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(14, 11), Some(4));
+        assert_eq!(mod_inverse(6, 9), None);
+        assert_eq!(mod_inverse(0, 5), None);
+    }
+
+    #[test]
+    fn digit_helpers() {
+        assert_eq!((digit_count(0), digit_count(9), digit_count(10)), (1, 1, 2));
+        assert_eq!(digit_count(u64::MAX), 20);
+        assert_eq!(
+            (pow10(19), pow10(20)),
+            (Some(10_000_000_000_000_000_000), None)
+        );
+
+        assert_eq!(concat_digits(12, 345), Some(12345));
+        assert_eq!(concat_digits(12, 0), Some(120));
+        assert_eq!(concat_digits(1_844_674_407_370_955_161, 5), Some(u64::MAX));
+        // Overflow in the addition and in the multiplication
+        assert_eq!(concat_digits(1_844_674_407_370_955_161, 6), None);
+        assert_eq!(concat_digits(1_844_674_407_370_955_162, 0), None);
+        assert_eq!(concat_digits(1, u64::MAX), None);
+
+        assert_eq!(strip_digits(12345, 45), Some(123));
+        assert_eq!(strip_digits(12345, 12345), Some(0));
+        assert_eq!(strip_digits(12345, 44), None);
+        assert_eq!(strip_digits(120, 0), Some(12));
+        assert_eq!(strip_digits(u64::MAX, 5), Some(1_844_674_407_370_955_161));
+        assert_eq!(strip_digits(u64::MAX, u64::MAX), None);
+    }
+
+    #[test]
+    fn isqrt_near_perfect_squares() {
+        assert_eq!(
+            (isqrt(0), isqrt(1), isqrt(3), isqrt(4), isqrt(99)),
+            (0, 1, 1, 2, 9)
+        );
+        let root = u32::MAX as u64;
+        assert_eq!(isqrt(root * root), root);
+        assert_eq!(isqrt(root * root - 1), root - 1);
+        assert_eq!(isqrt(u64::MAX), root);
+        // f64 rounds these up to a value whose square overflows
+        assert_eq!(isqrt(u64::MAX - 1), root);
+    }
+
+    #[test]
+    fn crt_solves_coprime_and_shared_moduli() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt(&[(1, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+        assert_eq!(crt(&[(-1, 5)]), Some((4, 5)));
+        assert_eq!(crt(&[]), Some((0, 1)));
+    }
+
+    #[test]
+    fn crt_lcm_beyond_i64() {
+        let (a, b) = (1_000_000_000_000_000_003, 1_000_000_000_000_000_009);
+        let (x, modulus) = crt(&[(1, a), (2, b)]).unwrap();
+        assert_eq!(modulus, a as i128 * b as i128);
+        assert_eq!((x % a as i128, x % b as i128), (1, 2));
+    }
+
+    #[test]
+    #[should_panic(expected = "must be positive")]
+    fn crt_rejects_zero_modulus() {
+        crt(&[(1, 3), (0, 0)]);
+    }
+}
@@ -0,0 +1,14 @@
+pub mod grid;
+pub mod point;
+pub mod parse;
+pub mod graph;
+pub mod search;
+pub mod cycle;
+pub mod counter;
+pub mod bitgrid;
+pub mod math;
+pub mod geometry;
+pub mod aho_corasick;
+pub mod sparse_grid;
+pub mod interval;
+pub mod union_find;
@@ -0,0 +1,325 @@
+// Parsers for the input shapes that keep coming back: number lists,
+// `key: values` lines, blank-line separated sections and character grids.
+// Line parsers report the column of the offending field, `parse_lines` and
+// `Section::parse_lines` add the line number on top.
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::utils::grid::Grid;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    // 1-based, 0 while the line is not known yet
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+pub type ParseResult<T> = Result<T, ParseError>;
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, message: impl Into<String>) -> Self {
+        ParseError {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    // Error about `field`, which must be a slice of `line`
+    fn in_line(line: &str, field: &str, message: impl Into<String>) -> Self {
+        ParseError::new(0, column_of(line, field), message)
+    }
+
+    fn with_line(mut self, line: usize) -> Self {
+        if self.line == 0 {
+            self.line = line;
+        }
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.line > 0 {
+            write!(f, "line {}, ", self.line)?;
+        }
+        write!(f, "column {}: {}", self.column, self.message)
+    }
+}
+
+impl Error for ParseError {}
+
+// 1-based character column of `field` within `line`
+fn column_of(line: &str, field: &str) -> usize {
+    let offset = (field.as_ptr() as usize)
+        .saturating_sub(line.as_ptr() as usize)
+        .min(line.len());
+    line[..offset].chars().count() + 1
+}
+
+fn parse_field<T: FromStr>(line: &str, field: &str) -> ParseResult<T>
+where
+    T::Err: fmt::Display,
+{
+    field
+        .parse::<T>()
+        .map_err(|err| ParseError::in_line(line, field, format!("'{}': {}", field, err)))
+}
+
+// Every run of digits in the line, a directly preceding '-' makes it negative
+pub fn ints<T: FromStr>(line: &str) -> ParseResult<Vec<T>>
+where
+    T::Err: fmt::Display,
+{
+    number_fields(line, true)
+        .map(|field| parse_field(line, field))
+        .collect()
+}
+
+// Every run of digits in the line, '-' is treated as a separator
+pub fn uints<T: FromStr>(line: &str) -> ParseResult<Vec<T>>
+where
+    T::Err: fmt::Display,
+{
+    number_fields(line, false)
+        .map(|field| parse_field(line, field))
+        .collect()
+}
+
+fn number_fields(line: &str, signed: bool) -> impl Iterator<Item = &str> {
+    let bytes = line.as_bytes();
+    let mut i = 0;
+    std::iter::from_fn(move || {
+        while i < bytes.len() && !bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        if i == bytes.len() {
+            return None;
+        }
+        let start = if signed && i > 0 && bytes[i - 1] == b'-' {
+            i - 1
+        } else {
+            i
+        };
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        Some(&line[start..i])
+    })
+}
+
+// Whitespace separated values, e.g. "7 6 4 2 1"
+pub fn words<T: FromStr>(line: &str) -> ParseResult<Vec<T>>
+where
+    T::Err: fmt::Display,
+{
+    line.split_whitespace()
+        .map(|field| parse_field(line, field))
+        .collect()
+}
+
+// Values separated by `separator`, e.g. "75,47,61" or "47|53"
+pub fn separated<T: FromStr>(line: &str, separator: char) -> ParseResult<Vec<T>>
+where
+    T::Err: fmt::Display,
+{
+    line.split(separator)
+        .map(|field| parse_field(line, field.trim()))
+        .collect()
+}
+
+// Exactly two values separated by `separator`
+pub fn pair<T: FromStr>(line: &str, separator: char) -> ParseResult<(T, T)>
+where
+    T::Err: fmt::Display,
+{
+    let Some((left, right)) = line.split_once(separator) else {
+        return Err(ParseError::in_line(
+            line,
+            line,
+            format!("expected two values separated by '{}'", separator),
+        ));
+    };
+    Ok((
+        parse_field(line, left.trim())?,
+        parse_field(line, right.trim())?,
+    ))
+}
+
+// "190: 10 19" -> (190, [10, 19])
+pub fn key_values<K: FromStr, V: FromStr>(line: &str) -> ParseResult<(K, Vec<V>)>
+where
+    K::Err: fmt::Display,
+    V::Err: fmt::Display,
+{
+    let Some((key, values)) = line.split_once(':') else {
+        return Err(ParseError::in_line(line, line, "expected 'key: values'"));
+    };
+    let key = parse_field(line, key.trim())?;
+    let values = values
+        .split_whitespace()
+        .map(|field| parse_field(line, field))
+        .collect::<ParseResult<_>>()?;
+    Ok((key, values))
+}
+
+// Applies `parse` to every line, counting lines from `first_line`
+fn parse_lines_from<T, F>(text: &str, first_line: usize, mut parse: F) -> ParseResult<Vec<T>>
+where
+    F: FnMut(&str) -> ParseResult<T>,
+{
+    text.lines()
+        .enumerate()
+        .map(|(index, line)| parse(line).map_err(|err| err.with_line(first_line + index)))
+        .collect()
+}
+
+pub fn parse_lines<T, F>(input: &str, parse: F) -> ParseResult<Vec<T>>
+where
+    F: FnMut(&str) -> ParseResult<T>,
+{
+    parse_lines_from(input, 1, parse)
+}
+
+// A block of lines between blank lines, remembering where it started
+#[derive(Clone, Copy, Debug)]
+pub struct Section<'a> {
+    pub first_line: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Section<'a> {
+    pub fn lines(&self) -> impl Iterator<Item = &'a str> {
+        self.text.lines()
+    }
+
+    pub fn parse_lines<T, F>(&self, parse: F) -> ParseResult<Vec<T>>
+    where
+        F: FnMut(&str) -> ParseResult<T>,
+    {
+        parse_lines_from(self.text, self.first_line, parse)
+    }
+}
+
+pub fn sections(input: &str) -> Vec<Section<'_>> {
+    let mut sections = vec![];
+    let mut start: Option<(usize, usize)> = None;
+    let mut offset = 0;
+
+    for (index, line) in input.split_inclusive('\n').enumerate() {
+        if line.trim().is_empty() {
+            if let Some((first_line, begin)) = start.take() {
+                sections.push(Section {
+                    first_line,
+                    text: &input[begin..offset],
+                });
+            }
+        } else if start.is_none() {
+            start = Some((index + 1, offset));
+        }
+        offset += line.len();
+    }
+    if let Some((first_line, begin)) = start {
+        sections.push(Section {
+            first_line,
+            text: &input[begin..],
+        });
+    }
+    sections
+}
+
+// Rectangular character grid, ragged lines are an error
+pub fn char_grid(input: &str) -> ParseResult<Grid<char>> {
+    grid_with(input, Some)
+}
+
+// Character grid where `parse_cell` returns None for characters it does not accept
+pub fn grid_with<T, F>(input: &str, mut parse_cell: F) -> ParseResult<Grid<T>>
+where
+    F: FnMut(char) -> Option<T>,
+{
+    let mut rows: Vec<Vec<T>> = vec![];
+    for (index, line) in input.lines().enumerate() {
+        let mut row = Vec::with_capacity(line.len());
+        for (column, c) in line.chars().enumerate() {
+            let cell = parse_cell(c).ok_or_else(|| {
+                ParseError::new(index + 1, column + 1, format!("unexpected '{}'", c))
+            })?;
+            row.push(cell);
+        }
+        if let Some(first) = rows.first() {
+            if row.len() != first.len() {
+                return Err(ParseError::new(
+                    index + 1,
+                    row.len().min(first.len()) + 1,
+                    format!("expected {} columns, found {}", first.len(), row.len()),
+                ));
+            }
+        }
+        rows.push(row);
+    }
+    Ok(Grid::from_rows(rows))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_lines_reports_line_and_column() {
+        let input = "7 6 4\n1 x 3\n9 9 9";
+        let err = parse_lines(input, words::<u32>).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        assert!(err.to_string().starts_with("line 2, column 3: 'x'"));
+
+        let parsed = parse_lines("1: 2 3\n4: 5", key_values::<u32, u32>).unwrap();
+        assert_eq!(parsed, [(1, vec![2, 3]), (4, vec![5])]);
+    }
+
+    #[test]
+    fn sections_keep_their_line_offset() {
+        let input = "47|53\n97|13\n\n\n75,47\n75,x";
+        let parts = sections(input);
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts[0].first_line, 1);
+        assert_eq!(parts[1].first_line, 5);
+        assert_eq!(parts[1].lines().collect::<Vec<_>>(), ["75,47", "75,x"]);
+
+        let err = parts[1]
+            .parse_lines(|line| separated::<u32>(line, ','))
+            .unwrap_err();
+        assert_eq!((err.line, err.column), (6, 4));
+        assert_eq!(
+            parts[0].parse_lines(|line| pair::<u32>(line, '|')),
+            Ok(vec![(47, 53), (97, 13)])
+        );
+    }
+
+    #[test]
+    fn char_grid_rejects_ragged_rows() {
+        let grid = char_grid("ab\ncd").unwrap();
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+
+        let err = char_grid("abc\nab\nabc").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.message, "expected 3 columns, found 2");
+
+        let err = grid_with("..\n.#", |c| (c == '.').then_some(0)).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+    }
+
+    #[test]
+    fn words_and_numbers() {
+        assert_eq!(words::<i32>("  7 -6\t4 "), Ok(vec![7, -6, 4]));
+        assert_eq!(words::<u32>(""), Ok(vec![]));
+        let err = words::<u8>("1 300").unwrap_err();
+        assert_eq!((err.line, err.column), (0, 3));
+        assert_eq!(err.to_string().split(':').next(), Some("column 3"));
+
+        assert_eq!(ints::<i64>("x=-3, y=12"), Ok(vec![-3, 12]));
+        assert_eq!(uints::<u64>("x=-3, y=12"), Ok(vec![3, 12]));
+    }
+}
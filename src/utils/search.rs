@@ -0,0 +1,342 @@
+// Shortest paths over implicit graphs. A search is described by its start
+// states, a neighbour function and a goal predicate, so the same code serves
+// grids, mazes with keys, or any other state space.
+//
+// For a grid maze, something like:
+//     bfs([start], |&p| grid.neighbors4(p).filter(|&n| grid.get_point(n) != Some(&'#')), |&p| p == end)
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+// A cheapest path, from one of the starts to the first goal reached
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Route<S, C> {
+    pub cost: C,
+    pub states: Vec<S>,
+}
+
+// Every state seen so far, addressed by index so the heap and the parent
+// links do not need to own or order states
+struct Visited<S, C> {
+    states: Vec<S>,
+    indices: HashMap<S, usize>,
+    costs: Vec<C>,
+    parents: Vec<Option<usize>>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> Visited<S, C> {
+    fn new() -> Self {
+        Visited {
+            states: vec![],
+            indices: HashMap::new(),
+            costs: vec![],
+            parents: vec![],
+        }
+    }
+
+    fn insert(&mut self, state: S, cost: C, parent: Option<usize>) -> usize {
+        let index = self.states.len();
+        self.indices.insert(state.clone(), index);
+        self.states.push(state);
+        self.costs.push(cost);
+        self.parents.push(parent);
+        index
+    }
+
+    fn route(&self, goal: usize) -> Route<S, C> {
+        let mut states = vec![];
+        let mut current = Some(goal);
+        while let Some(index) = current {
+            states.push(self.states[index].clone());
+            current = self.parents[index];
+        }
+        states.reverse();
+        Route {
+            cost: self.costs[goal],
+            states,
+        }
+    }
+}
+
+// Unweighted search, the cost is the number of steps
+pub fn bfs<S, I, N, G>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbors: N,
+    mut is_goal: G,
+) -> Option<Route<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+    N: FnMut(&S) -> I,
+    G: FnMut(&S) -> bool,
+{
+    let mut visited = Visited::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !visited.indices.contains_key(&start) {
+            queue.push_back(visited.insert(start, 0, None));
+        }
+    }
+
+    while let Some(index) = queue.pop_front() {
+        let current = visited.states[index].clone();
+        if is_goal(&current) {
+            return Some(visited.route(index));
+        }
+        let cost = visited.costs[index] + 1;
+        for next in neighbors(&current) {
+            if !visited.indices.contains_key(&next) {
+                queue.push_back(visited.insert(next, cost, Some(index)));
+            }
+        }
+    }
+    None
+}
+
+// Step counts to every state reachable from the starts
+pub fn bfs_distances<S, I, N>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbors: N,
+) -> HashMap<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+    N: FnMut(&S) -> I,
+{
+    let mut distances = HashMap::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !distances.contains_key(&start) {
+            distances.insert(start.clone(), 0);
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(current) = queue.pop_front() {
+        let distance = distances[&current] + 1;
+        for next in neighbors(&current) {
+            if !distances.contains_key(&next) {
+                distances.insert(next.clone(), distance);
+                queue.push_back(next);
+            }
+        }
+    }
+    distances
+}
+
+// Weighted search, `neighbors` yields each next state with the cost of the step
+pub fn dijkstra<S, C, I, N, G>(
+    starts: impl IntoIterator<Item = S>,
+    neighbors: N,
+    is_goal: G,
+) -> Option<Route<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+    N: FnMut(&S) -> I,
+    G: FnMut(&S) -> bool,
+{
+    astar(starts, neighbors, |_| C::default(), is_goal)
+}
+
+// Dijkstra guided by `heuristic`, which must never overestimate the
+// remaining cost to the nearest goal for the result to be optimal
+pub fn astar<S, C, I, N, H, G>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbors: N,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> Option<Route<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+    N: FnMut(&S) -> I,
+    H: FnMut(&S) -> C,
+    G: FnMut(&S) -> bool,
+{
+    let mut visited = Visited::new();
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        if !visited.indices.contains_key(&start) {
+            let estimate = heuristic(&start);
+            let index = visited.insert(start, C::default(), None);
+            heap.push(Reverse((estimate, C::default(), index)));
+        }
+    }
+
+    while let Some(Reverse((_, cost, index))) = heap.pop() {
+        // Skip entries that were superseded by a cheaper path
+        if cost > visited.costs[index] {
+            continue;
+        }
+        let current = visited.states[index].clone();
+        if is_goal(&current) {
+            return Some(visited.route(index));
+        }
+        for (next, step) in neighbors(&current) {
+            let next_cost = cost + step;
+            let next_index = match visited.indices.get(&next) {
+                Some(&known) if next_cost >= visited.costs[known] => continue,
+                Some(&known) => {
+                    visited.costs[known] = next_cost;
+                    visited.parents[known] = Some(index);
+                    known
+                }
+                None => visited.insert(next.clone(), next_cost, Some(index)),
+            };
+            heap.push(Reverse((
+                next_cost + heuristic(&next),
+                next_cost,
+                next_index,
+            )));
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::geometry::manhattan;
+    use crate::utils::grid::Grid;
+    use crate::utils::point::Point;
+
+    // Only one way through: down, right along the bottom, up, and around the wall
+    const MAZE: &str = "\
+S.#...
+.##.#.
+....#E";
+
+    // Several routes of different lengths around the walls
+    const OPEN: &str = "\
+S.....#.
+.####.#.
+.#......
+.#.####.
+...#...E";
+
+    fn open_neighbors(grid: &Grid<char>, point: Point) -> Vec<Point> {
+        grid.neighbors4(point)
+            .filter(|&n| grid.get_point(n) != Some(&'#'))
+            .collect()
+    }
+
+    fn locate(grid: &Grid<char>, cell: char) -> Point {
+        Point::from(grid.find(&cell).unwrap())
+    }
+
+    #[test]
+    fn bfs_reconstructs_the_path() {
+        let grid = Grid::parse(MAZE);
+        let (start, end) = (locate(&grid, 'S'), locate(&grid, 'E'));
+        let route = bfs([start], |&p| open_neighbors(&grid, p), |&p| p == end).unwrap();
+
+        assert_eq!(route.cost, 11);
+        let expected = [
+            (0, 0),
+            (0, 1),
+            (0, 2),
+            (1, 2),
+            (2, 2),
+            (3, 2),
+            (3, 1),
+            (3, 0),
+            (4, 0),
+            (5, 0),
+            (5, 1),
+            (5, 2),
+        ];
+        assert_eq!(route.states, expected.map(Point::from));
+    }
+
+    #[test]
+    fn searches_agree_on_cost() {
+        for maze in [MAZE, OPEN] {
+            let grid = Grid::parse(maze);
+            let (start, end) = (locate(&grid, 'S'), locate(&grid, 'E'));
+            let weighted = |p: &Point| open_neighbors(&grid, *p).into_iter().map(|n| (n, 1));
+
+            let steps = bfs([start], |&p| open_neighbors(&grid, p), |&p| p == end).unwrap();
+            let distances = bfs_distances([start], |&p| open_neighbors(&grid, p));
+            let cheapest = dijkstra([start], weighted, |&p| p == end).unwrap();
+            let guided = astar([start], weighted, |&p| manhattan(p, end), |&p| p == end).unwrap();
+
+            assert_eq!(distances[&end], steps.cost);
+            assert_eq!(cheapest.cost, steps.cost as i64);
+            assert_eq!(guided.cost, steps.cost as i64);
+            assert_eq!(guided.states.len(), steps.cost + 1);
+        }
+    }
+
+    #[test]
+    fn dijkstra_prefers_cheaper_detours() {
+        // Entering a cell costs its digit, the direct route crosses the 9
+        let grid = Grid::parse_with("091\n111", |c| c.to_digit(10).unwrap() as i64);
+        let (start, end) = (Point::new(0, 0), Point::new(2, 0));
+        let neighbors = |p: &Point| {
+            grid.neighbors4(*p)
+                .map(|n| (n, *grid.get_point(n).unwrap()))
+                .collect::<Vec<_>>()
+        };
+
+        let cheapest = dijkstra([start], neighbors, |&p| p == end).unwrap();
+        let guided = astar([start], neighbors, |&p| manhattan(p, end), |&p| p == end).unwrap();
+        let shortest = bfs(
+            [start],
+            |p| neighbors(p).into_iter().map(|(n, _)| n),
+            |&p| p == end,
+        );
+
+        assert_eq!(cheapest.cost, 4);
+        assert_eq!(cheapest.states.len(), 5);
+        assert_eq!(guided, cheapest);
+        assert_eq!(shortest.unwrap().cost, 2);
+    }
+
+    #[test]
+    fn multiple_starts_and_goals() {
+        let grid = Grid::parse(MAZE);
+        let starts = [Point::new(0, 0), Point::new(5, 0)];
+        let goals = [Point::new(3, 2), Point::new(5, 2)];
+        let weighted = |p: &Point| open_neighbors(&grid, *p).into_iter().map(|n| (n, 1));
+
+        let steps = bfs(starts, |&p| open_neighbors(&grid, p), |p| goals.contains(p)).unwrap();
+        let cheapest = dijkstra(starts, weighted, |p| goals.contains(p)).unwrap();
+        let guided = astar(
+            starts,
+            weighted,
+            |&p| goals.iter().map(|&g| manhattan(p, g)).min().unwrap(),
+            |p| goals.contains(p),
+        )
+        .unwrap();
+
+        let expected = [(5, 0), (5, 1), (5, 2)].map(Point::from);
+        assert_eq!(steps.states, expected);
+        assert_eq!(cheapest.states, expected);
+        assert_eq!(guided.states, expected);
+        assert_eq!(cheapest.cost, 2);
+
+        // A start that already is a goal costs nothing
+        let route = bfs(starts, |&p| open_neighbors(&grid, p), |&p| p == starts[1]).unwrap();
+        assert_eq!(route.cost, 0);
+        assert_eq!(route.states, vec![starts[1]]);
+    }
+
+    #[test]
+    fn unreachable_goal() {
+        let grid = Grid::parse("S#E");
+        let (start, end) = (locate(&grid, 'S'), locate(&grid, 'E'));
+        let weighted = |p: &Point| open_neighbors(&grid, *p).into_iter().map(|n| (n, 1));
+
+        assert_eq!(
+            bfs([start], |&p| open_neighbors(&grid, p), |&p| p == end),
+            None
+        );
+        assert_eq!(dijkstra([start], weighted, |&p| p == end), None);
+        assert!(!bfs_distances([start], |&p| open_neighbors(&grid, p)).contains_key(&end));
+    }
+}
@@ -0,0 +1,294 @@
+// Unbounded grid that only stores occupied cells, for maps that grow in any
+// direction or use negative coordinates. Methods named like those of the dense
+// `Grid` (`get`, `get_point`, `find`, ...) take the same arguments and return
+// the same types. Where the behaviour differs, the name does too: `insert`
+// returns the previous value like `HashMap::insert`, `occupied_bounds` may be
+// None, and the parsers only store the cells they are told to. The exceptions
+// are `iter`, which has no particular order, and the neighbours, which never
+// hit an edge.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::utils::geometry::Rect;
+use crate::utils::point::{Direction, Point};
+
+#[derive(Clone, Debug)]
+pub struct SparseGrid<T> {
+    cells: HashMap<(i64, i64), T>,
+    default: T,
+    // Bounding box of the occupied cells, None while the grid is empty
+    bounds: Option<Rect>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new(default: T) -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+            default,
+            bounds: None,
+        }
+    }
+
+    // Cells for which `parse_cell` returns None stay empty
+    pub fn parse_occupied_with<F>(input: &str, default: T, mut parse_cell: F) -> Self
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut grid = SparseGrid::new(default);
+        for (y, line) in input.lines().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                if let Some(cell) = parse_cell(c) {
+                    grid.insert(x as i64, y as i64, cell);
+                }
+            }
+        }
+        grid
+    }
+
+    pub fn default_value(&self) -> &T {
+        &self.default
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn occupied_bounds(&self) -> Option<Rect> {
+        self.bounds
+    }
+
+    // Size of the bounding box, 0 while empty
+    pub fn width(&self) -> usize {
+        self.bounds
+            .map_or(0, |rect| (rect.max.x - rect.min.x + 1) as usize)
+    }
+
+    pub fn height(&self) -> usize {
+        self.bounds
+            .map_or(0, |rect| (rect.max.y - rect.min.y + 1) as usize)
+    }
+
+    // Unlike `Grid::contains` this is not about bounds, every position exists
+    pub fn is_occupied(&self, x: i64, y: i64) -> bool {
+        self.cells.contains_key(&(x, y))
+    }
+
+    pub fn get(&self, x: i64, y: i64) -> Option<&T> {
+        self.cells.get(&(x, y))
+    }
+
+    pub fn get_or_default(&self, x: i64, y: i64) -> &T {
+        self.get(x, y).unwrap_or(&self.default)
+    }
+
+    pub fn get_mut(&mut self, x: i64, y: i64) -> Option<&mut T> {
+        self.cells.get_mut(&(x, y))
+    }
+
+    // Returns the previous value of the cell
+    pub fn insert(&mut self, x: i64, y: i64, value: T) -> Option<T> {
+        let point = Point::new(x, y);
+        self.bounds = Some(match self.bounds {
+            None => Rect::new(point, point),
+            Some(rect) => Rect::new(
+                Point::new(rect.min.x.min(x), rect.min.y.min(y)),
+                Point::new(rect.max.x.max(x), rect.max.y.max(y)),
+            ),
+        });
+        self.cells.insert((x, y), value)
+    }
+
+    pub fn get_point(&self, point: Point) -> Option<&T> {
+        self.get(point.x, point.y)
+    }
+
+    pub fn get_point_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.get_mut(point.x, point.y)
+    }
+
+    pub fn insert_point(&mut self, point: Point, value: T) -> Option<T> {
+        self.insert(point.x, point.y, value)
+    }
+
+    pub fn remove(&mut self, x: i64, y: i64) -> Option<T> {
+        let removed = self.cells.remove(&(x, y))?;
+        // Only a cell on the edge of the bounding box can shrink it
+        if let Some(rect) = self.bounds {
+            if x == rect.min.x || x == rect.max.x || y == rect.min.y || y == rect.max.y {
+                self.bounds = self.compute_bounds();
+            }
+        }
+        Some(removed)
+    }
+
+    fn compute_bounds(&self) -> Option<Rect> {
+        let mut positions = self.cells.keys();
+        let &(x, y) = positions.next()?;
+        let first = Rect::new(Point::new(x, y), Point::new(x, y));
+        Some(positions.fold(first, |rect, &(x, y)| {
+            Rect::new(
+                Point::new(rect.min.x.min(x), rect.min.y.min(y)),
+                Point::new(rect.max.x.max(x), rect.max.y.max(y)),
+            )
+        }))
+    }
+
+    // Occupied cells in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = ((i64, i64), &T)> {
+        self.cells.iter().map(|(&pos, cell)| (pos, cell))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = (i64, i64)> + '_ {
+        self.cells.keys().copied()
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        self.positions().map(Point::from)
+    }
+
+    pub fn iter_points(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.iter().map(|(pos, cell)| (Point::from(pos), cell))
+    }
+
+    pub fn find(&self, value: &T) -> Option<(i64, i64)>
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .find(|(_, cell)| *cell == value)
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = (i64, i64)> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(pos, _)| pos)
+    }
+
+    // Unlike the dense grid there is no edge, so every neighbour exists
+    pub fn neighbors4(&self, point: Point) -> impl Iterator<Item = Point> {
+        Direction::ORTHOGONAL
+            .into_iter()
+            .map(move |direction| point + direction)
+    }
+
+    pub fn neighbors8(&self, point: Point) -> impl Iterator<Item = Point> {
+        Direction::ALL
+            .into_iter()
+            .map(move |direction| point + direction)
+    }
+}
+
+impl SparseGrid<char> {
+    // Every character other than `empty` is stored
+    pub fn parse_occupied(input: &str, empty: char) -> Self {
+        SparseGrid::parse_occupied_with(input, empty, |c| (c != empty).then_some(c))
+    }
+}
+
+// Renders the bounding box of the occupied cells
+impl<T: fmt::Display> fmt::Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some(rect) = self.bounds else {
+            return Ok(());
+        };
+        for y in rect.min.y..=rect.max.y {
+            if y > rect.min.y {
+                writeln!(f)?;
+            }
+            for x in rect.min.x..=rect.max.x {
+                write!(f, "{}", self.get_or_default(x, y))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rect(min: (i64, i64), max: (i64, i64)) -> Option<Rect> {
+        Some(Rect::new(Point::from(min), Point::from(max)))
+    }
+
+    #[test]
+    fn bounds_grow_and_shrink() {
+        let mut grid = SparseGrid::new('.');
+        assert_eq!(grid.occupied_bounds(), None);
+        assert_eq!((grid.width(), grid.height()), (0, 0));
+
+        grid.insert(0, 0, '#');
+        grid.insert(3, -2, '#');
+        grid.insert(1, 1, '#');
+        grid.insert(-1, 0, '#');
+        assert_eq!(grid.occupied_bounds(), rect((-1, -2), (3, 1)));
+        assert_eq!((grid.width(), grid.height()), (5, 4));
+
+        // Interior cells and empty cells leave the box alone
+        assert_eq!(grid.remove(0, 0), Some('#'));
+        assert_eq!(grid.remove(2, 2), None);
+        assert_eq!(grid.occupied_bounds(), rect((-1, -2), (3, 1)));
+
+        // Edge cells shrink it to the remaining cells
+        grid.remove(3, -2);
+        assert_eq!(grid.occupied_bounds(), rect((-1, 0), (1, 1)));
+        grid.remove(-1, 0);
+        assert_eq!(grid.occupied_bounds(), rect((1, 1), (1, 1)));
+        grid.remove(1, 1);
+        assert_eq!(grid.occupied_bounds(), None);
+        assert!(grid.is_empty());
+    }
+
+    #[test]
+    fn display_with_negative_coordinates() {
+        let mut grid = SparseGrid::new('.');
+        grid.insert(-2, -1, 'a');
+        grid.insert(1, -1, 'b');
+        grid.insert(0, 1, 'c');
+        assert_eq!(grid.to_string(), "a..b\n....\n..c.");
+        assert_eq!(SparseGrid::new('.').to_string(), "");
+    }
+
+    #[test]
+    fn parse_keeps_only_occupied_cells() {
+        let grid = SparseGrid::parse_occupied("..#\n#..\n...", '.');
+        assert_eq!(grid.len(), 2);
+        assert!(grid.is_occupied(2, 0));
+        assert!(!grid.is_occupied(0, 0));
+        assert_eq!(grid.get(0, 0), None);
+        assert_eq!(grid.get_or_default(0, 0), &'.');
+        assert_eq!(grid.get_or_default(-5, 7), &'.');
+        assert_eq!(grid.get_point(Point::new(0, 1)), Some(&'#'));
+        // Trailing empty rows are not part of the occupied region
+        assert_eq!(grid.to_string(), "..#\n#..");
+    }
+
+    #[test]
+    fn insert_returns_the_previous_value() {
+        let mut grid = SparseGrid::new(0);
+        assert_eq!(grid.insert(-3, 4, 1), None);
+        assert_eq!(grid.insert_point(Point::new(-3, 4), 2), Some(1));
+        *grid.get_point_mut(Point::new(-3, 4)).unwrap() += 5;
+        assert_eq!(grid.get(-3, 4), Some(&7));
+        assert_eq!(grid.len(), 1);
+        assert_eq!(grid.find(&7), Some((-3, 4)));
+    }
+
+    #[test]
+    fn neighbors_are_unbounded() {
+        let grid: SparseGrid<char> = SparseGrid::new('.');
+        let origin = Point::ORIGIN;
+        assert_eq!(grid.neighbors4(origin).count(), 4);
+        assert_eq!(grid.neighbors8(origin).count(), 8);
+        assert!(grid.neighbors4(origin).any(|p| p == Point::new(0, -1)));
+    }
+}
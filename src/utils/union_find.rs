@@ -0,0 +1,169 @@
+// Disjoint sets over the elements 0..n, with path compression and union by
+// rank, plus a helper that labels connected regions of equal grid cells.
+
+use std::collections::HashMap;
+
+use crate::utils::grid::Grid;
+
+#[derive(Clone, Debug)]
+pub struct UnionFind {
+    parent: Vec<usize>,
+    rank: Vec<u8>,
+    // Only kept up to date for roots
+    size: Vec<usize>,
+    components: usize,
+}
+
+impl UnionFind {
+    // n singleton sets
+    pub fn new(n: usize) -> Self {
+        UnionFind {
+            parent: (0..n).collect(),
+            rank: vec![0; n],
+            size: vec![1; n],
+            components: n,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    // Representative of the set containing x
+    pub fn find(&mut self, x: usize) -> usize {
+        let root = self.root(x);
+        let mut current = x;
+        while current != root {
+            current = std::mem::replace(&mut self.parent[current], root);
+        }
+        root
+    }
+
+    // Like `find` but without compressing, for lookups through a shared reference
+    fn root(&self, mut x: usize) -> usize {
+        while self.parent[x] != x {
+            x = self.parent[x];
+        }
+        x
+    }
+
+    // Returns false if a and b were already in the same set
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        if self.rank[a] < self.rank[b] {
+            (a, b) = (b, a);
+        }
+        if self.rank[a] == self.rank[b] {
+            self.rank[a] += 1;
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        self.components -= 1;
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    // Size of the set containing x
+    pub fn size(&self, x: usize) -> usize {
+        self.size[self.root(x)]
+    }
+
+    pub fn component_count(&self) -> usize {
+        self.components
+    }
+
+    // Members of every set in increasing order, sets ordered by their
+    // smallest member
+    pub fn components(&self) -> impl Iterator<Item = Vec<usize>> {
+        let mut index: HashMap<usize, usize> = HashMap::new();
+        let mut groups: Vec<Vec<usize>> = vec![];
+        for x in 0..self.len() {
+            let next = groups.len();
+            let group = *index.entry(self.root(x)).or_insert(next);
+            if group == next {
+                groups.push(vec![]);
+            }
+            groups[group].push(x);
+        }
+        groups.into_iter()
+    }
+}
+
+// Labels orthogonally connected regions of equal cells with 0, 1, ... in
+// reading order of their first cell. Returns the labels and the region count.
+pub fn label_regions<T: PartialEq>(grid: &Grid<T>) -> (Grid<usize>, usize) {
+    let width = grid.width();
+    let index = |x: i64, y: i64| y as usize * width + x as usize;
+    let mut sets = UnionFind::new(width * grid.height());
+    for ((x, y), cell) in grid.iter() {
+        for (nx, ny) in [(x + 1, y), (x, y + 1)] {
+            if grid.get(nx, ny) == Some(cell) {
+                sets.union(index(x, y), index(nx, ny));
+            }
+        }
+    }
+
+    let mut labels = Grid::new(width, grid.height(), 0);
+    for (label, component) in sets.components().enumerate() {
+        for i in component {
+            labels.set((i % width) as i64, (i / width) as i64, label);
+        }
+    }
+    (labels, sets.component_count())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn union_merges_sets() {
+        let mut sets = UnionFind::new(6);
+        assert_eq!(sets.component_count(), 6);
+        assert!(sets.union(0, 3));
+        assert!(sets.union(3, 5));
+        assert!(sets.union(1, 2));
+        assert!(!sets.union(5, 0));
+
+        assert!(sets.connected(0, 5));
+        assert!(!sets.connected(0, 1));
+        assert_eq!(sets.component_count(), 3);
+        assert_eq!((sets.size(5), sets.size(2), sets.size(4)), (3, 2, 1));
+        assert_eq!(
+            sets.components().collect::<Vec<_>>(),
+            [vec![0, 3, 5], vec![1, 2], vec![4]]
+        );
+    }
+
+    #[test]
+    fn long_chains_stay_consistent() {
+        let mut sets = UnionFind::new(1000);
+        for i in 1..1000 {
+            sets.union(i - 1, i);
+        }
+        assert_eq!(sets.component_count(), 1);
+        assert_eq!(sets.size(0), 1000);
+        assert_eq!(sets.find(999), sets.find(0));
+        assert!(UnionFind::new(0).components().next().is_none());
+    }
+
+    #[test]
+    fn label_regions_on_a_non_square_grid() {
+        // The Bs split the As in two, the corner Bs only touch other letters
+        let grid = Grid::parse("AAAB\nABBA\nBAAA");
+        let (labels, count) = label_regions(&grid);
+        let expected = Grid::from_rows(vec![vec![0, 0, 0, 1], vec![0, 2, 2, 3], vec![4, 3, 3, 3]]);
+        assert_eq!(count, 5);
+        assert_eq!(labels, expected);
+    }
+}
@@ -11,27 +11,27 @@
 -> Error Handling: consider using 'unwrap_or_else' instead of 'unwrap'
 */
 
+use crate::utils::counter::Counter;
+use crate::utils::parse::{parse_lines, words};
 use itertools::Itertools;
-use std::collections::HashMap;
+use rand::rngs::StdRng;
+use rand::Rng;
 use std::fs;
 use std::path::Path;
 
-pub fn parse_line(line: &str) -> Vec<u32> {
-    line.split_whitespace()
-        .map(|s: &str| -> u32 { s.parse::<u32>().unwrap() })
-        .collect()
+pub fn parse_lists(input: &str) -> Vec<Vec<u32>> {
+    parse_lines(input, words).unwrap_or_else(|err| panic!("{}", err))
 }
 
 pub fn parse_input_tuple(input: &str) -> (Vec<u32>, Vec<u32>) {
-    input
-        .lines()
-        .map(parse_line)
+    parse_lists(input)
+        .into_iter()
         .map(|ints: Vec<u32>| -> (u32, u32) { (ints[0], ints[1]) })
         .unzip()
 }
 
 pub fn parse_input_vec(input: &str) -> Vec<Vec<u32>> {
-    input.lines().map(parse_line).fold(
+    parse_lists(input).into_iter().fold(
         vec![Vec::<u32>::new(), Vec::<u32>::new()],
         |mut v: Vec<Vec<u32>>, e: Vec<u32>| {
             v[0].push(e[0]);
@@ -41,23 +41,40 @@ pub fn parse_input_vec(input: &str) -> Vec<Vec<u32>> {
     )
 }
 
-pub fn part1(left: &Vec<u32>, right: &Vec<u32>) -> u32 {
+pub fn parse_input_sorted(input: &str) -> (Vec<u32>, Vec<u32>) {
+    let (mut left, mut right) = parse_input_tuple(input);
+    left.sort_unstable();
+    right.sort_unstable();
+    (left, right)
+}
+
+pub fn parse_input_vec_sorted(input: &str) -> (Vec<u32>, Vec<u32>) {
+    let mut vecs = parse_input_vec(input);
+    vecs.iter_mut().for_each(|v| v.sort_unstable());
+    vecs.into_iter().collect_tuple().unwrap()
+}
+
+pub fn part1(left: &[u32], right: &[u32]) -> u32 {
     left.iter()
         .zip(right.iter())
         .map(|(l, r)| u32::abs_diff(*l, *r))
         .sum()
 }
 
-pub fn part2(left: &Vec<u32>, right: &Vec<u32>) -> u32 {
-    let map = right.iter().fold(HashMap::new(), |mut m, x| {
-        *m.entry(x).or_insert(0) += 1;
-        m
-    });
+pub fn part2(left: &[u32], right: &[u32]) -> u32 {
+    let counts = right.iter().collect::<Counter<_>>();
+
+    left.iter().map(|x| x * counts.count(&x) as u32).sum()
+}
 
-    left.iter().map(|x| x * map.get(x).unwrap_or(&0)).sum()
+pub fn generate_input(rng: &mut StdRng) -> String {
+    (0..rng.gen_range(1..=20))
+        .map(|_| format!("{}   {}", rng.gen_range(0..100), rng.gen_range(0..100)))
+        .collect::<Vec<_>>()
+        .join("\n")
 }
 
-pub fn exec(input: &Path) -> () {
+pub fn exec(input: &Path) {
     let message: String = fs::read_to_string(input).unwrap();
 
     ///////////////////////////////////////////////////////////////////////////////////
@@ -84,6 +101,6 @@ pub fn exec(input: &Path) -> () {
     let (left, right) = vecs.iter().collect_tuple().unwrap();
 
     println!("Vector approach:");
-    println!("  Part 1: {:?}", part1(&left, &right));
-    println!("  Part 2: {:?}", part2(&left, &right));
+    println!("  Part 1: {:?}", part1(left, right));
+    println!("  Part 2: {:?}", part2(left, right));
 }
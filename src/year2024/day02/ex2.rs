@@ -0,0 +1,145 @@
+/* Learnings and Takeaways:
+
+-> Checking Conditions: 'all' can sometimes be used instead of 'map' and 'fold'
+-> Adjacent Comparison: 'window' generates overlapping slices of a certain size
+-> Preallocate Vec Capacity: 'Vec::with_capacity'
+*/
+
+use crate::utils::parse::{parse_lines, words};
+use rand::rngs::StdRng;
+use rand::Rng;
+use std::fs;
+use std::path::Path;
+
+pub fn parse_input(input: &str) -> Vec<Vec<u32>> {
+    parse_lines(input, words).unwrap_or_else(|err| panic!("{}", err))
+}
+
+pub fn is_monotonic_dec(level: &[u32]) -> bool {
+    level.windows(2).all(|w| -> bool { w[0] > w[1] })
+}
+
+pub fn is_monotonic_inc(level: &[u32]) -> bool {
+    level.windows(2).all(|w| -> bool { w[0] < w[1] })
+}
+
+pub fn at_most_three_apart(level: &[u32]) -> bool {
+    level
+        .windows(2)
+        .all(|w| -> bool { u32::abs_diff(w[0], w[1]) <= 3 })
+}
+
+pub fn check_level(level: &[u32]) -> bool {
+    at_most_three_apart(level) && (is_monotonic_inc(level) || is_monotonic_dec(level))
+}
+
+pub fn part_one(levels: &[Vec<u32>]) -> u32 {
+    levels.iter().filter(|l| check_level(l)).count() as u32
+}
+
+pub fn generate_subvecs_iterative(vec: &[u32]) -> Vec<Vec<u32>> {
+    let mut vecs = Vec::with_capacity(vec.len() + 1);
+    vecs.push(vec.to_vec());
+    for index in 0..vec.len() {
+        let mut subvec = vec.to_vec();
+        subvec.remove(index);
+        vecs.push(subvec)
+    }
+    vecs
+}
+
+pub fn generate_subvecs_functional(vec: &[u32]) -> Vec<Vec<u32>> {
+    (0..vec.len())
+        .map(|i| {
+            let mut subvec = vec.to_vec();
+            subvec.remove(i);
+            subvec
+        })
+        .chain(std::iter::once(vec.to_vec()))
+        .collect()
+}
+
+pub fn count_dampened(levels: &[Vec<u32>], generate_subvecs: fn(&[u32]) -> Vec<Vec<u32>>) -> u32 {
+    levels
+        .iter()
+        .map(|level| generate_subvecs(level))
+        .filter(|subvec| subvec.iter().any(|l| check_level(l)))
+        .count() as u32
+}
+
+pub fn part_two(levels: &[Vec<u32>]) -> u32 {
+    count_dampened(levels, generate_subvecs_functional)
+}
+
+pub fn part_two_iterative(levels: &[Vec<u32>]) -> u32 {
+    count_dampened(levels, generate_subvecs_iterative)
+}
+
+pub fn offending_level(level: &[u32]) -> Option<usize> {
+    let increasing = level.len() > 1 && level[0] < level[1];
+    level
+        .windows(2)
+        .position(|w| {
+            let ordered = if increasing { w[0] < w[1] } else { w[0] > w[1] };
+            !ordered || u32::abs_diff(w[0], w[1]) > 3
+        })
+        .map(|i| i + 1)
+}
+
+pub fn explain(input: &str) -> Vec<String> {
+    parse_input(input)
+        .iter()
+        .map(|level| {
+            let report = level
+                .iter()
+                .map(|l| l.to_string())
+                .collect::<Vec<_>>()
+                .join(" ");
+            match offending_level(level) {
+                None => format!("{}: safe", report),
+                Some(index) => {
+                    let dampened = (0..level.len()).find(|&i| {
+                        let mut subvec = level.to_vec();
+                        subvec.remove(i);
+                        check_level(&subvec)
+                    });
+                    let verdict = match dampened {
+                        Some(i) => format!("safe when removing level {} at index {}", level[i], i),
+                        None => String::from("unsafe even with the dampener"),
+                    };
+                    format!(
+                        "{}: unsafe at level {} (index {}), {}",
+                        report, level[index], index, verdict
+                    )
+                }
+            }
+        })
+        .collect()
+}
+
+pub fn generate_input(rng: &mut StdRng) -> String {
+    (0..rng.gen_range(1..=20))
+        .map(|_| {
+            let mut level: u32 = rng.gen_range(1..=50);
+            (0..rng.gen_range(2..=8))
+                .map(|_| {
+                    level = level.saturating_add_signed(rng.gen_range(-4..=4)).max(1);
+                    level.to_string()
+                })
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn exec(input: &Path) {
+    let message: String = fs::read_to_string(input).unwrap();
+    let vecs = parse_input(&message);
+
+    let result_one = part_one(&vecs);
+    println!("Result part one: {:?}", result_one);
+
+    let result_two = part_two(&vecs);
+    println!("Result part two: {:?}", result_two);
+}
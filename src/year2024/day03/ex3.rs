@@ -0,0 +1,162 @@
+use crate::utils::aho_corasick::AhoCorasick;
+use rand::rngs::StdRng;
+use rand::Rng;
+use std::fs;
+use std::path::Path;
+
+pub fn part_one(input: &str) -> u32 {
+    let re = regex::Regex::new(r"mul\(([0-9]{1,3}),([0-9]{1,3})\)").unwrap();
+    re.captures_iter(input)
+        .map(|c| {
+            let (_, [first, second]) = c.extract();
+            first.parse::<u32>().unwrap() * second.parse::<u32>().unwrap()
+        })
+        .sum()
+}
+
+// The input starts enabled, and the don't() sentinel closes the last enabled
+// span, which would otherwise have no don't() to match up to
+pub fn part_two(input: &str) -> u32 {
+    let re = fancy_regex::Regex::new(r"(?s)(?<=do\(\))(.*?)(?=don't\(\))").unwrap();
+    let text = format!("do(){}don't()", input);
+    re.captures_iter(&text)
+        .map(|c| {
+            let capture = c.unwrap().get(0).unwrap().as_str();
+            part_one(capture)
+        })
+        .sum()
+}
+
+// Number of 1 to 3 digits at the start of `bytes`, and the bytes after it
+fn parse_number(bytes: &[u8]) -> Option<(u32, &[u8])> {
+    let digits = bytes
+        .iter()
+        .take(3)
+        .take_while(|b| b.is_ascii_digit())
+        .count();
+    let value = bytes[..digits]
+        .iter()
+        .fold(0, |acc, b| acc * 10 + (b - b'0') as u32);
+    (digits > 0).then_some((value, &bytes[digits..]))
+}
+
+// The product of "X,Y)" following a "mul(", and the length of "X,Y)"
+fn mul_product(bytes: &[u8]) -> Option<(u32, usize)> {
+    let (first, rest) = parse_number(bytes)?;
+    let (second, rest) = parse_number(rest.strip_prefix(b",")?)?;
+    let length = bytes.len() - rest.len() + 1;
+    rest.starts_with(b")").then_some((first * second, length))
+}
+
+// Finds all instruction tokens in one pass and parses the arguments by hand
+pub fn scan(input: &str, conditionals: bool) -> u32 {
+    let tokens = AhoCorasick::new(["mul(", "do()", "don't()"]);
+    let bytes = input.as_bytes();
+    let mut enabled = true;
+    let mut sum = 0;
+
+    for token in tokens.find_iter(input) {
+        match token.pattern {
+            0 if enabled => {
+                sum += mul_product(&bytes[token.end..]).map_or(0, |(product, _)| product)
+            }
+            1 => enabled = true,
+            2 => enabled = !conditionals,
+            _ => {}
+        }
+    }
+    sum
+}
+
+pub fn part_one_scan(input: &str) -> u32 {
+    scan(input, false)
+}
+
+pub fn part_two_scan(input: &str) -> u32 {
+    scan(input, true)
+}
+
+// Random corrupted memory for differential testing: valid and broken mul
+// instructions, do() and don't() toggles and noise, spread over a few lines
+pub fn generate_input(rng: &mut StdRng) -> String {
+    const NOISE: [&str; 10] = [
+        "mul", "mul(", "(", ")", ",", "do(", "don't", " ", "what()", "\n",
+    ];
+    // Up to 4 digits, one more than a valid argument may have
+    let number = |rng: &mut StdRng| {
+        let digits = rng.gen_range(1..=4);
+        rng.gen_range(0..10u32.pow(digits)).to_string()
+    };
+
+    let mut text = String::new();
+    for _ in 0..rng.gen_range(0..=40) {
+        match rng.gen_range(0..10) {
+            0..=3 => {
+                let (first, second) = (number(rng), number(rng));
+                text.push_str(&format!("mul({},{})", first, second));
+            }
+            4 => text.push_str("do()"),
+            5 => text.push_str("don't()"),
+            _ => text.push_str(NOISE[rng.gen_range(0..NOISE.len())]),
+        }
+    }
+    text
+}
+
+// Spans between a do() and the next don't(), the input starts enabled and the
+// last span runs to the end of the input
+pub fn explain(input: &str) -> Vec<String> {
+    let tokens = AhoCorasick::new(["mul(", "do()", "don't()"]);
+    let bytes = input.as_bytes();
+    let mut spans = vec![];
+    let mut current = Some((0, vec![]));
+
+    for token in tokens.find_iter(input) {
+        match token.pattern {
+            0 => {
+                if let Some((_, products)) = &mut current {
+                    if let Some((product, length)) = mul_product(&bytes[token.end..]) {
+                        let instruction = &input[token.start..token.end + length];
+                        products.push((token.start, instruction, product));
+                    }
+                }
+            }
+            1 if current.is_none() => current = Some((token.end, vec![])),
+            2 => {
+                if let Some((start, products)) = current.take() {
+                    spans.push((start, token.start, products));
+                }
+            }
+            _ => {}
+        }
+    }
+    if let Some((start, products)) = current {
+        spans.push((start, input.len(), products));
+    }
+
+    let mut lines = vec![];
+    for (start, end, products) in spans {
+        let sum: u32 = products.iter().map(|(_, _, p)| p).sum();
+        lines.push(format!(
+            "Enabled span {}..{}: {} mul instructions, sum {}",
+            start,
+            end,
+            products.len(),
+            sum
+        ));
+        for (position, instruction, product) in products {
+            lines.push(format!("    {} at {} = {}", instruction, position, product));
+        }
+    }
+    lines
+}
+
+pub fn exec(input: &Path) {
+    let message: String = fs::read_to_string(input).unwrap();
+
+    let result_one = part_one(&message);
+    println!("Result part one: {:?}", result_one);
+
+    let result_two = part_two(&message);
+    println!("Result part two: {:?}", result_two);
+}
@@ -0,0 +1,86 @@
+use crate::utils::aho_corasick::AhoCorasick;
+use crate::utils::grid::Grid;
+use std::fs;
+use std::path::Path;
+
+pub fn parse_input(input: &str) -> Grid<char> {
+    Grid::parse(input)
+}
+
+fn push_line<'a>(lines: &mut Vec<String>, characters: impl Iterator<Item = &'a char>) {
+    lines.push(characters.collect());
+}
+
+pub fn get_horizontal_lines(grid: &Grid<char>) -> Vec<String> {
+    let mut lines = vec![];
+    for row in grid.rows() {
+        push_line(&mut lines, row.iter());
+    }
+    lines
+}
+
+pub fn get_vertical_lines(grid: &Grid<char>) -> Vec<String> {
+    get_horizontal_lines(&grid.transpose())
+}
+
+pub fn get_diagonal_lines(grid: &Grid<char>) -> Vec<String> {
+    let mut lines = vec![];
+    for diagonal in grid.diagonals().chain(grid.anti_diagonals()) {
+        push_line(&mut lines, diagonal.into_iter().map(|(_, c)| c));
+    }
+    lines
+}
+
+pub fn get_lines(grid: Grid<char>) -> Vec<String> {
+    let mut lines = get_horizontal_lines(&grid);
+    let mut vert = get_vertical_lines(&grid);
+    let mut diag = get_diagonal_lines(&grid);
+    lines.append(&mut vert);
+    lines.append(&mut diag);
+    lines
+}
+
+// Searching for the reversed word as well covers both reading directions,
+// overlapping matches like "XMASAMX" count twice
+pub fn count_occurences(list: &[String]) -> u32 {
+    let words = AhoCorasick::new(["XMAS", "SAMX"]);
+    list.iter().map(|line| words.count(line) as u32).sum()
+}
+
+pub fn check_x_mas(grid: &Grid<char>, x: i64, y: i64) -> bool {
+    let is_mas = |a: Option<&char>, b: Option<&char>| {
+        matches!((a, b), (Some('M'), Some('S')) | (Some('S'), Some('M')))
+    };
+    let has_a = grid.get(x, y) == Some(&'A');
+    let has_fist_mas = is_mas(grid.get(x - 1, y - 1), grid.get(x + 1, y + 1));
+    let has_second_mas = is_mas(grid.get(x - 1, y + 1), grid.get(x + 1, y - 1));
+
+    has_a && has_fist_mas && has_second_mas
+}
+
+pub fn find_x_mas(grid: &Grid<char>) -> u32 {
+    grid.find_all(&'A')
+        .filter(|&(x, y)| check_x_mas(grid, x, y))
+        .count() as u32
+}
+
+pub fn part_one(input: &str) -> u32 {
+    let grid = parse_input(input);
+    let list = get_lines(grid);
+    count_occurences(&list)
+}
+
+pub fn part_two(input: &str) -> u32 {
+    let grid = parse_input(input);
+    find_x_mas(&grid)
+}
+
+pub fn exec(input: &Path) {
+    let message: String = fs::read_to_string(input).unwrap();
+
+    let result_one = part_one(&message);
+    println!("Result part one: {:?}", result_one);
+
+    let result_two = part_two(&message);
+    println!("Result part two: {:?}", result_two);
+}
@@ -0,0 +1,107 @@
+use crate::utils::graph::{Cycle, Graph};
+use crate::utils::parse::{pair, sections, separated};
+use std::fs;
+use std::path::Path;
+
+// The rules come first, the updates follow after a blank line
+pub fn parse_input_rules(input: &str) -> Vec<(u32, u32)> {
+    sections(input)
+        .first()
+        .expect("input has no ordering rules")
+        .parse_lines(|line| pair(line, '|'))
+        .unwrap_or_else(|err| panic!("{}", err))
+}
+
+pub fn parse_input_updates(input: &str) -> Vec<Vec<u32>> {
+    sections(input)
+        .get(1)
+        .expect("input needs a blank line between the rules and the updates")
+        .parse_lines(|line| separated(line, ','))
+        .unwrap_or_else(|err| panic!("{}", err))
+}
+
+pub fn is_correct(update: &[u32], rules: &[(u32, u32)]) -> bool {
+    for first in 0..update.len() {
+        for second in first..update.len() {
+            let anti_rule = (update[second], update[first]);
+            if rules.contains(&anti_rule) {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+// The full rule set is cyclic, only the rules between the pages of a single
+// update have to form an ordering
+pub fn fix_ordering(update: &[u32], rules: &Graph<u32>) -> Result<Vec<u32>, Cycle<u32>> {
+    rules.topological_sort_subset(update)
+}
+
+pub fn part_one(input: &str) -> u32 {
+    let rules = parse_input_rules(input);
+    let updates = parse_input_updates(input);
+
+    updates
+        .into_iter()
+        .filter(|u| is_correct(u, &rules))
+        .fold(0, |mut acc, vec| {
+            acc += vec[vec.len() / 2];
+            acc
+        })
+}
+
+pub fn part_two(input: &str) -> u32 {
+    let rules = parse_input_rules(input);
+    let updates = parse_input_updates(input);
+    let graph = Graph::from_edges(rules.iter().copied());
+
+    updates
+        .into_iter()
+        .filter(|u| !is_correct(u, &rules))
+        .map(|u| {
+            fix_ordering(&u, &graph)
+                .unwrap_or_else(|cycle| panic!("Rules contain a cycle: {}", cycle))
+        })
+        .fold(0, |mut acc, vec| {
+            acc += vec[vec.len() / 2];
+            acc
+        })
+}
+
+pub fn explain(input: &str) -> Vec<String> {
+    let rules = parse_input_rules(input);
+    let updates = parse_input_updates(input);
+    let graph = Graph::from_edges(rules.iter().copied());
+    let join = |update: &[u32]| {
+        update
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    };
+
+    updates
+        .iter()
+        .filter(|u| !is_correct(u, &rules))
+        .map(|u| match fix_ordering(u, &graph) {
+            Ok(fixed) => format!(
+                "{} -> {} (middle {})",
+                join(u),
+                join(&fixed),
+                fixed[fixed.len() / 2]
+            ),
+            Err(cycle) => format!("{} -> no ordering, cycle {}", join(u), cycle),
+        })
+        .collect()
+}
+
+pub fn exec(input: &Path) {
+    let input: String = fs::read_to_string(input).unwrap();
+
+    let result_one = part_one(&input);
+    println!("Result part one: {:?}", result_one);
+
+    let result_two = part_two(&input);
+    println!("Result part two: {:?}", result_two);
+}
@@ -0,0 +1,132 @@
+use crate::progress;
+use crate::utils::bitgrid::{BitGrid, BitGrid3};
+use crate::utils::grid::Grid;
+use crate::utils::point::{Direction, Point};
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+use std::time::Instant;
+
+type Guard = (Point, Direction);
+
+pub fn parse_input(input: &str) -> Grid<char> {
+    Grid::parse(input)
+}
+
+pub fn find_start_move(map: &Grid<char>) -> Guard {
+    map.iter_points()
+        .find_map(|(pos, &cell)| Some((pos, Direction::from_char(cell)?)))
+        .expect("Input does not have a starting postition!")
+}
+
+pub fn is_inside(map: &Grid<char>, pos: &Point) -> bool {
+    map.contains_point(*pos)
+}
+
+pub fn is_blocked(map: &Grid<char>, pos: &Point) -> bool {
+    map.get_point(*pos) == Some(&'#')
+}
+
+pub fn next_position(state: &Guard) -> Point {
+    state.0 + state.1
+}
+
+pub fn advance_guard(map: &Grid<char>, state: &Guard) -> Guard {
+    let next_pos = next_position(state);
+    if is_blocked(map, &next_pos) {
+        (state.0, state.1.turn_right())
+    } else {
+        (next_pos, state.1)
+    }
+}
+
+// One bit per position and direction the guard can face
+fn visited_states(map: &Grid<char>) -> BitGrid3 {
+    BitGrid3::new(map.width(), map.height(), Direction::ORTHOGONAL.len())
+}
+
+fn insert_state(visited: &mut BitGrid3, (pos, dir): &Guard) -> bool {
+    let dir = dir
+        .orthogonal_index()
+        .expect("The guard only moves orthogonally");
+    visited.insert(pos.x, pos.y, dir)
+}
+
+pub fn generate_moves(map: &Grid<char>, start_state: &Guard) -> Vec<Guard> {
+    // The visited states stop the walk on a loop, the vector keeps their order
+
+    let mut moves = visited_states(map);
+    let mut result = vec![];
+    let mut curr_move = *start_state;
+
+    while is_inside(map, &curr_move.0) && insert_state(&mut moves, &curr_move) {
+        result.push(curr_move);
+        curr_move = advance_guard(map, &curr_move);
+    }
+    result
+}
+
+pub fn count_unique_pos(map: &Grid<char>, moves: &[Guard]) -> u32 {
+    let mut positions = BitGrid::new(map.width(), map.height());
+    for (pos, _) in moves {
+        positions.insert(pos.x, pos.y);
+    }
+    positions.len() as u32
+}
+
+pub fn make_map_add_obst(map: &Grid<char>, obs_pos: Point) -> Grid<char> {
+    let mut new_map = map.clone();
+    new_map.set_point(obs_pos, '#');
+    new_map
+}
+
+// `visited` is cleared first, so one grid can be reused for every candidate
+pub fn detect_loop(map: &Grid<char>, start: &Guard, visited: &mut BitGrid3) -> bool {
+    visited.clear();
+    let mut curr_move = *start;
+
+    while is_inside(map, &curr_move.0) && insert_state(visited, &curr_move) {
+        curr_move = advance_guard(map, &curr_move);
+    }
+    is_inside(map, &curr_move.0)
+}
+
+pub fn part_one(input: &str) -> u32 {
+    let map = parse_input(input);
+    let start_move = find_start_move(&map);
+    let moves = generate_moves(&map, &start_move);
+    count_unique_pos(&map, &moves)
+}
+
+pub fn part_two(input: &str) -> u32 {
+    let map = parse_input(input);
+    let start_move = find_start_move(&map);
+    let moves = generate_moves(&map, &start_move);
+    let candidates = moves
+        .into_iter()
+        .map(|state| make_map_add_obst(&map, state.0))
+        .collect::<HashSet<_>>();
+
+    let mut visited = visited_states(&map);
+    progress::start(candidates.len());
+    candidates
+        .into_iter()
+        .filter(|new_map| {
+            progress::tick();
+            detect_loop(new_map, &start_move, &mut visited)
+        })
+        .count() as u32
+}
+
+pub fn exec(input: &Path) {
+    let input: String = fs::read_to_string(input).unwrap();
+
+    let result_one = part_one(&input);
+    println!("Result part one: {:?}", result_one);
+
+    let start = Instant::now();
+    let result_two = part_two(&input);
+    let elapsed = start.elapsed();
+    println!("Result part two: {:?}", result_two);
+    println!("Part two took: {} seconds", elapsed.as_secs_f64());
+}
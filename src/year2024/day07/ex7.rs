@@ -0,0 +1,214 @@
+use crate::progress;
+use crate::utils::math::concat_digits;
+use crate::utils::parse::{key_values, parse_lines};
+use rand::rngs::StdRng;
+use rand::Rng;
+use std::fs;
+use std::path::Path;
+
+// None once the result no longer fits in a u64, no goal can be reached from there
+type Operator = fn(u64, u64) -> Option<u64>;
+
+pub fn parse_input(input: &str) -> Vec<(u64, Vec<u64>)> {
+    parse_lines(input, key_values).unwrap_or_else(|err| panic!("{}", err))
+}
+
+pub fn add(a: u64, b: u64) -> Option<u64> {
+    a.checked_add(b)
+}
+
+pub fn multiply(a: u64, b: u64) -> Option<u64> {
+    a.checked_mul(b)
+}
+
+pub fn concat(a: u64, b: u64) -> Option<u64> {
+    concat_digits(a, b)
+}
+
+pub fn is_solvable_one(goal: u64, sum: u64, values: &[u64]) -> bool {
+    if values.is_empty() || sum > goal {
+        goal == sum
+    } else {
+        let (cur_val, remaining_vals) = (values[0], &values[1..]);
+        let solvable = |next: Option<u64>| {
+            next.is_some_and(|next| is_solvable_one(goal, next, remaining_vals))
+        };
+        solvable(add(sum, cur_val)) || solvable(multiply(sum, cur_val))
+    }
+}
+
+// The first value starts the sum, otherwise '0 * first' would silently drop it
+pub fn has_solution_one(eq: &(u64, Vec<u64>)) -> bool {
+    let Some((first, rest)) = eq.1.split_first() else {
+        return false;
+    };
+    is_solvable_one(eq.0, *first, rest)
+}
+
+pub fn is_solvable_two(goal: u64, sum: u64, values: &[u64]) -> bool {
+    if values.is_empty() || sum > goal {
+        goal == sum
+    } else {
+        let (cur_val, remaining_vals) = (values[0], &values[1..]);
+        let solvable = |next: Option<u64>| {
+            next.is_some_and(|next| is_solvable_two(goal, next, remaining_vals))
+        };
+        solvable(add(sum, cur_val))
+            || solvable(multiply(sum, cur_val))
+            || solvable(concat(sum, cur_val))
+    }
+}
+
+pub fn has_solution_two(eq: &(u64, Vec<u64>)) -> bool {
+    let Some((first, rest)) = eq.1.split_first() else {
+        return false;
+    };
+    is_solvable_two(eq.0, *first, rest)
+}
+
+// Tries every combination of operators instead of recursing with pruning
+pub fn is_solvable_brute_force(goal: u64, values: &[u64], operators: &[Operator]) -> bool {
+    let Some((first, rest)) = values.split_first() else {
+        return false;
+    };
+    let combinations = operators.len().pow(rest.len() as u32);
+    (0..combinations).any(|mut combination| {
+        rest.iter().try_fold(*first, |acc, &value| {
+            let op = operators[combination % operators.len()];
+            combination /= operators.len();
+            op(acc, value)
+        }) == Some(goal)
+    })
+}
+
+pub fn part_one_brute_force(input: &str) -> u64 {
+    parse_input(input)
+        .into_iter()
+        .filter(|(goal, values)| is_solvable_brute_force(*goal, values, &[add, multiply]))
+        .map(|(a, _)| a)
+        .sum()
+}
+
+pub fn part_two_brute_force(input: &str) -> u64 {
+    parse_input(input)
+        .into_iter()
+        .filter(|(goal, values)| is_solvable_brute_force(*goal, values, &[add, multiply, concat]))
+        .map(|(a, _)| a)
+        .sum()
+}
+
+// Random equations for differential testing, about half of them solvable.
+// The values are large enough for some operator combinations to overflow,
+// while the goals stay small enough for the sum of all goals to fit.
+pub fn generate_input(rng: &mut StdRng) -> String {
+    const MAX_GOAL: u64 = 1_000_000_000_000_000;
+    let operators: [Operator; 3] = [add, multiply, concat];
+    (0..rng.gen_range(1..=8))
+        .map(|_| {
+            let values: Vec<u64> = (0..rng.gen_range(1..=8))
+                .map(|_| {
+                    let digits = rng.gen_range(1..=7);
+                    rng.gen_range(1..10u64.pow(digits))
+                })
+                .collect();
+            let result = values[1..].iter().try_fold(values[0], |acc, &value| {
+                operators[rng.gen_range(0..operators.len())](acc, value)
+            });
+            let goal = match result {
+                Some(result) if result < MAX_GOAL && rng.gen_bool(0.5) => result,
+                Some(result) if result < MAX_GOAL => result + 1,
+                _ => rng.gen_range(1..MAX_GOAL),
+            };
+            let values = values.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+            format!("{}: {}", goal, values.join(" "))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn find_operators(
+    goal: u64,
+    sum: u64,
+    values: &[u64],
+    with_concat: bool,
+) -> Option<Vec<&'static str>> {
+    let Some((&cur_val, remaining_vals)) = values.split_first() else {
+        return (goal == sum).then(Vec::new);
+    };
+    if sum > goal {
+        return None;
+    }
+
+    let mut operators: Vec<(&'static str, Operator)> = vec![("+", add), ("*", multiply)];
+    if with_concat {
+        operators.push(("||", concat));
+    }
+    operators.into_iter().find_map(|(symbol, op)| {
+        let next = op(sum, cur_val)?;
+        find_operators(goal, next, remaining_vals, with_concat).map(|mut ops| {
+            ops.insert(0, symbol);
+            ops
+        })
+    })
+}
+
+pub fn explain(input: &str) -> Vec<String> {
+    parse_input(input)
+        .iter()
+        .map(|(goal, values)| {
+            let Some((first, rest)) = values.split_first() else {
+                return format!("{}: no values", goal);
+            };
+            let solution = find_operators(*goal, *first, rest, false)
+                .map(|ops| (ops, "part one"))
+                .or_else(|| find_operators(*goal, *first, rest, true).map(|ops| (ops, "part two")));
+
+            match solution {
+                Some((ops, part)) => {
+                    let terms = rest
+                        .iter()
+                        .zip(ops)
+                        .fold(first.to_string(), |acc, (value, op)| {
+                            format!("{} {} {}", acc, op, value)
+                        });
+                    format!("{} = {} ({})", goal, terms, part)
+                }
+                None => format!("{}: no solution", goal),
+            }
+        })
+        .collect()
+}
+
+pub fn part_one(input: &str) -> u64 {
+    let equations = parse_input(input);
+    equations
+        .into_iter()
+        .filter(has_solution_one)
+        .map(|(a, _)| a)
+        .reduce(|a, b| a + b)
+        .unwrap_or(0)
+}
+
+pub fn part_two(input: &str) -> u64 {
+    let equations = parse_input(input);
+    progress::start(equations.len());
+    equations
+        .into_iter()
+        .filter(|eq| {
+            progress::tick();
+            has_solution_two(eq)
+        })
+        .map(|(a, _)| a)
+        .reduce(|a, b| a + b)
+        .unwrap_or(0)
+}
+
+pub fn exec(input: &Path) {
+    let input: String = fs::read_to_string(input).unwrap();
+
+    let result_one = part_one(&input);
+    println!("Result part one: {:?}", result_one);
+
+    let result_two = part_two(&input);
+    println!("Result part two: {:?}", result_two);
+}
@@ -0,0 +1,144 @@
+use crate::utils::geometry::{line_points, reflect};
+use crate::utils::grid::Grid;
+use crate::utils::point::Point;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+//use std::{cmp::Eq, hash::Hash, iter::FromIterator};
+
+type AntennaPair = (Point, Point);
+type Poles = Vec<Point>;
+
+pub fn parse_input(input: &str) -> Grid<char> {
+    Grid::parse(input)
+}
+
+// pub struct AntennaMap<K: Eq + Hash, V>(HashMap<K, Vec<V>>);
+
+// impl<K: Eq + Hash, V> FromIterator<(K, V)> for AntennaMap<K, V> {
+//     fn from_iter<I>(tuples: I) -> Self
+//     where
+//         I: IntoIterator<Item = (K, V)>,
+//     {
+//         let mut m = HashMap::new();
+//         for (k, v) in tuples {
+//             m.entry(k).or_insert_with(Vec::new).push(v)
+//         }
+//         Self(m)
+//     }
+// }
+
+// pub fn parse_map(input: &Vec<Vec<char>>) -> HashMap<char, Vec<(usize, usize)>> {
+//     input
+//         .iter()
+//         .enumerate()
+//         .flat_map(|(x, row)| {
+//             row.iter()
+//                 .enumerate()
+//                 .filter_map(move |(y, &cell)| match cell.is_alphanumeric() {
+//                     true => Some((cell, (x, y))),
+//                     false => None,
+//                 })
+//         })
+//         .fold(HashMap::new(), |mut acc, (k, v)| {
+//             acc.entry(k).or_insert_with(Vec::new).push(v);
+//             acc
+//         })
+// }
+
+pub fn parse_map(input: &Grid<char>) -> HashMap<char, Vec<Point>> {
+    let mut map = HashMap::new();
+
+    for (pos, &cell) in input.iter_points() {
+        if cell.is_alphanumeric() {
+            map.entry(cell).or_insert_with(Vec::new).push(pos);
+        }
+    }
+
+    map
+}
+
+// pub fn antenna_positions(map: &HashMap<char, Vec<(usize, usize)>>) -> HashSet<(usize, usize)> {
+//     map.clone().into_values().flatten().collect()
+// }
+
+pub fn antenna_pairs(antennas: &[Point]) -> Vec<AntennaPair> {
+    antennas
+        .iter()
+        .enumerate()
+        .flat_map(|(i, a)| antennas.iter().skip(i + 1).map(|b| (*a, *b)))
+        .collect()
+}
+
+pub fn antinode_primary_poles((a, b): &AntennaPair, grid: &Grid<char>) -> Poles {
+    let north = reflect(*b, *a);
+    let south = reflect(*a, *b);
+
+    [north, south]
+        .into_iter()
+        .filter(|&pole| grid.contains_point(pole))
+        .collect()
+}
+
+pub fn antinode_all_poles((a, b): &AntennaPair, grid: &Grid<char>) -> Poles {
+    line_points(*a, *b, grid.bounds())
+}
+
+pub fn antinode_positions<G>(antennas: &[Point], gen_func: &mut G) -> Vec<Point>
+where
+    G: FnMut(&AntennaPair) -> Poles,
+{
+    antenna_pairs(antennas).iter().flat_map(gen_func).collect()
+}
+
+pub fn valid_antinode_positions<G>(
+    antenna_map: &HashMap<char, Vec<Point>>,
+    gen_func: &mut G,
+) -> Vec<Point>
+where
+    G: FnMut(&AntennaPair) -> Poles,
+{
+    antenna_map
+        .values()
+        .flat_map(|x| antinode_positions(x, gen_func))
+        .collect()
+}
+
+pub fn part_one(input: &str) -> u64 {
+    let grid = parse_input(input);
+    let map = parse_map(&grid);
+
+    // Higher-Ranked Trait Bounds (HRTB) and Dynamic Dispatch
+    // Explicit &mut dyn for<'a> FnMut
+    // explicit higher-ranked trait bound (for<'a>), ensuring that the closure is compatible with any lifetime.
+    let mut gen_func: &mut dyn for<'a> FnMut(&'a AntennaPair) -> Poles =
+        &mut |pair| antinode_primary_poles(pair, &grid);
+
+    valid_antinode_positions(&map, &mut gen_func)
+        .into_iter()
+        .collect::<HashSet<_>>()
+        .len() as u64
+}
+
+pub fn part_two(input: &str) -> u64 {
+    let grid = parse_input(input);
+    let map = parse_map(&grid);
+
+    let mut gen_func: &mut dyn for<'a> FnMut(&'a AntennaPair) -> Poles =
+        &mut |pair| antinode_all_poles(pair, &grid);
+
+    valid_antinode_positions(&map, &mut gen_func)
+        .into_iter()
+        .collect::<HashSet<_>>()
+        .len() as u64
+}
+
+pub fn exec(input: &Path) {
+    let input: String = fs::read_to_string(input).unwrap();
+
+    let result_one = part_one(&input);
+    println!("Result part one: {:?}", result_one);
+
+    let result_two = part_two(&input);
+    println!("Result part two: {:?}", result_two);
+}
@@ -0,0 +1,177 @@
+use crate::input_file_path;
+use crate::solver::{Solver, Variant};
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+
+pub const YEAR: u16 = 2024;
+
+pub fn solvers() -> Vec<Solver> {
+    vec![
+        Solver {
+            year: YEAR,
+            day: 1,
+            source: include_str!("day01/ex1.rs"),
+            variants: vec![
+                Variant {
+                    name: "tuple",
+                    part_one: |input| {
+                        let (left, right) = day01::ex1::parse_input_sorted(input);
+                        day01::ex1::part1(&left, &right).to_string()
+                    },
+                    part_two: |input| {
+                        let (left, right) = day01::ex1::parse_input_sorted(input);
+                        day01::ex1::part2(&left, &right).to_string()
+                    },
+                },
+                Variant {
+                    name: "vector",
+                    part_one: |input| {
+                        let (left, right) = day01::ex1::parse_input_vec_sorted(input);
+                        day01::ex1::part1(&left, &right).to_string()
+                    },
+                    part_two: |input| {
+                        let (left, right) = day01::ex1::parse_input_vec_sorted(input);
+                        day01::ex1::part2(&left, &right).to_string()
+                    },
+                },
+            ],
+            explain: None,
+            generate: Some(day01::ex1::generate_input),
+        },
+        Solver {
+            year: YEAR,
+            day: 2,
+            source: include_str!("day02/ex2.rs"),
+            variants: vec![
+                Variant {
+                    name: "functional",
+                    part_one: |input| {
+                        day02::ex2::part_one(&day02::ex2::parse_input(input)).to_string()
+                    },
+                    part_two: |input| {
+                        day02::ex2::part_two(&day02::ex2::parse_input(input)).to_string()
+                    },
+                },
+                Variant {
+                    name: "iterative",
+                    part_one: |input| {
+                        day02::ex2::part_one(&day02::ex2::parse_input(input)).to_string()
+                    },
+                    part_two: |input| {
+                        day02::ex2::part_two_iterative(&day02::ex2::parse_input(input)).to_string()
+                    },
+                },
+            ],
+            explain: Some(day02::ex2::explain),
+            generate: Some(day02::ex2::generate_input),
+        },
+        Solver {
+            year: YEAR,
+            day: 3,
+            source: include_str!("day03/ex3.rs"),
+            variants: vec![
+                Variant {
+                    name: "regex",
+                    part_one: |input| day03::ex3::part_one(input).to_string(),
+                    part_two: |input| day03::ex3::part_two(input).to_string(),
+                },
+                Variant {
+                    name: "scan",
+                    part_one: |input| day03::ex3::part_one_scan(input).to_string(),
+                    part_two: |input| day03::ex3::part_two_scan(input).to_string(),
+                },
+            ],
+            explain: Some(day03::ex3::explain),
+            generate: Some(day03::ex3::generate_input),
+        },
+        Solver {
+            year: YEAR,
+            day: 4,
+            source: include_str!("day04/ex4.rs"),
+            variants: vec![Variant {
+                name: "default",
+                part_one: |input| day04::ex4::part_one(input).to_string(),
+                part_two: |input| day04::ex4::part_two(input).to_string(),
+            }],
+            explain: None,
+            generate: None,
+        },
+        Solver {
+            year: YEAR,
+            day: 5,
+            source: include_str!("day05/ex5.rs"),
+            variants: vec![Variant {
+                name: "default",
+                part_one: |input| day05::ex5::part_one(input).to_string(),
+                part_two: |input| day05::ex5::part_two(input).to_string(),
+            }],
+            explain: Some(day05::ex5::explain),
+            generate: None,
+        },
+        Solver {
+            year: YEAR,
+            day: 6,
+            source: include_str!("day06/ex6.rs"),
+            variants: vec![Variant {
+                name: "default",
+                part_one: |input| day06::ex6::part_one(input).to_string(),
+                part_two: |input| day06::ex6::part_two(input).to_string(),
+            }],
+            explain: None,
+            generate: None,
+        },
+        Solver {
+            year: YEAR,
+            day: 7,
+            source: include_str!("day07/ex7.rs"),
+            variants: vec![
+                Variant {
+                    name: "recursive",
+                    part_one: |input| day07::ex7::part_one(input).to_string(),
+                    part_two: |input| day07::ex7::part_two(input).to_string(),
+                },
+                Variant {
+                    name: "brute-force",
+                    part_one: |input| day07::ex7::part_one_brute_force(input).to_string(),
+                    part_two: |input| day07::ex7::part_two_brute_force(input).to_string(),
+                },
+            ],
+            explain: Some(day07::ex7::explain),
+            generate: Some(day07::ex7::generate_input),
+        },
+        Solver {
+            year: YEAR,
+            day: 8,
+            source: include_str!("day08/ex8.rs"),
+            variants: vec![Variant {
+                name: "default",
+                part_one: |input| day08::ex8::part_one(input).to_string(),
+                part_two: |input| day08::ex8::part_two(input).to_string(),
+            }],
+            explain: None,
+            generate: None,
+        },
+    ]
+}
+
+pub fn exec_day(day: u8) {
+    let input = input_file_path(YEAR, day, "input.txt");
+    match day {
+        1 => day01::ex1::exec(&input),
+        2 => day02::ex2::exec(&input),
+        3 => day03::ex3::exec(&input),
+        4 => day04::ex4::exec(&input),
+        5 => day05::ex5::exec(&input),
+        6 => day06::ex6::exec(&input),
+        7 => day07::ex7::exec(&input),
+        8 => day08::ex8::exec(&input),
+        _ => println!("To be implemented soon ... "),
+    }
+}